@@ -434,6 +434,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
             ..
         } = c;
         self.check_name_case(name_location, &name, Named::Constant);
+        self.track_deprecation_since(&deprecation, location);
         // If the constant's name matches an unqualified import, emit a warning:
         self.check_shadow_import(&name, c.location, environment);
 
@@ -534,6 +535,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
             return_annotation,
             end_position: end_location,
             deprecation,
+            must_use,
             external_erlang,
             external_javascript,
             return_type: (),
@@ -579,6 +581,11 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
             self.ensure_annotations_present(&arguments, return_annotation.as_ref(), location);
         }
 
+        if must_use {
+            self.track_feature_usage(FeatureKind::MustUseAnnotation, location);
+        }
+        self.track_deprecation_since(&deprecation, location);
+
         let has_body = !body.is_empty();
         let definition = FunctionDefinition {
             has_body,
@@ -735,6 +742,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
             location,
             implementations,
             purity,
+            must_use,
         };
 
         environment.insert_variable(
@@ -759,6 +767,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
             name: Some((name_location, name.clone())),
             publicity,
             deprecation,
+            must_use,
             arguments: typed_arguments,
             body_start,
             end_position: end_location,
@@ -960,6 +969,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
                 attribute_location: Some(location),
             } => self.track_feature_usage(FeatureKind::InternalAnnotation, location),
         }
+        self.track_deprecation_since(&deprecation, location);
 
         let constructors: Vec<RecordConstructor<Arc<Type>>> = constructors
             .into_iter()
@@ -971,6 +981,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
                      arguments,
                      documentation,
                      deprecation: constructor_deprecation,
+                     publicity: constructor_publicity,
                  }| {
                     self.check_name_case(name_location, &name, Named::CustomTypeVariant);
                     if constructor_deprecation.is_deprecated() {
@@ -979,6 +990,13 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
                             location,
                         );
                     }
+                    self.track_deprecation_since(&constructor_deprecation, location);
+                    if constructor_publicity.is_internal() {
+                        self.track_feature_usage(
+                            FeatureKind::VariantWithInternalAnnotation,
+                            location,
+                        );
+                    }
 
                     let preregistered_fn = environment
                         .get_variable(&name)
@@ -1015,6 +1033,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
                         arguments,
                         documentation,
                         deprecation: constructor_deprecation,
+                        publicity: constructor_publicity,
                     }
                 },
             )
@@ -1122,9 +1141,12 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
             }
 
             // If the constructor belongs to an opaque type then it's going to be
-            // considered as private.
+            // considered as private. Otherwise it shares the publicity of its
+            // type, unless it has been marked as internal on its own.
             let value_constructor_publicity = if *opaque {
                 Publicity::Private
+            } else if constructor.publicity.is_internal() {
+                constructor.publicity
             } else {
                 *publicity
             };
@@ -1236,6 +1258,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
                     .documentation
                     .as_ref()
                     .map(|(_, documentation)| documentation.clone()),
+                publicity: constructor.publicity,
             });
             environment.insert_variable(
                 constructor.name.clone(),
@@ -1417,6 +1440,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
         }
 
         self.check_name_case(*name_location, name, Named::TypeAlias);
+        self.track_deprecation_since(deprecation, *location);
 
         environment
             .references
@@ -1526,6 +1550,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
             external_erlang,
             external_javascript,
             deprecation,
+            must_use,
             end_position: _,
             body: _,
             body_start: _,
@@ -1609,6 +1634,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
             location: *location,
             implementations: *implementations,
             purity: *purity,
+            must_use: *must_use,
         };
         environment.insert_variable(
             name.clone(),
@@ -1668,6 +1694,12 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
         }
     }
 
+    fn track_deprecation_since(&mut self, deprecation: &Deprecation, location: SrcSpan) {
+        if let Deprecation::Deprecated { since: Some(_), .. } = deprecation {
+            self.track_feature_usage(FeatureKind::DeprecationSince, location);
+        }
+    }
+
     fn check_shadow_import(
         &mut self,
         name: &EcoString,
@@ -1863,6 +1895,7 @@ fn generalise_function(
         name,
         publicity,
         deprecation,
+        must_use,
         arguments,
         body,
         return_annotation,
@@ -1902,6 +1935,7 @@ fn generalise_function(
         location,
         implementations,
         purity,
+        must_use,
     };
     environment.insert_variable(
         name.clone(),
@@ -1926,6 +1960,7 @@ fn generalise_function(
         name: Some((name_location, name)),
         publicity,
         deprecation,
+        must_use,
         arguments,
         end_position: end_location,
         body_start,
@@ -7,7 +7,9 @@ mod tests;
 pub mod visit;
 
 pub use self::typed::{InvalidExpression, TypedExpr};
-pub use self::untyped::{FunctionLiteralKind, UntypedExpr};
+pub use self::untyped::{
+    FunctionLiteralKind, InterpolatedStringSegment, SubjectlessClause, UntypedExpr,
+};
 
 pub use self::constant::{Constant, TypedConstant, UntypedConstant};
 
@@ -300,6 +302,9 @@ pub struct RecordConstructor<T> {
     pub arguments: Vec<RecordConstructorArg<T>>,
     pub documentation: Option<(u32, EcoString)>,
     pub deprecation: Deprecation,
+    /// A variant of a public type is public unless it has the `@internal`
+    /// attribute, and a variant of a private type is always private.
+    pub publicity: Publicity,
 }
 
 impl<A> RecordConstructor<A> {
@@ -748,6 +753,7 @@ pub struct Function<T, Expr> {
     pub body: Vec<Statement<T, Expr>>,
     pub publicity: Publicity,
     pub deprecation: Deprecation,
+    pub must_use: bool,
     pub return_annotation: Option<TypeAst>,
     pub return_type: T,
     pub documentation: Option<(u32, EcoString)>,
@@ -1639,6 +1645,7 @@ impl CallArg<UntypedExpr> {
             UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
             | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Block { .. }
             | UntypedExpr::Fn { .. }
             | UntypedExpr::List { .. }
@@ -1646,6 +1653,8 @@ impl CallArg<UntypedExpr> {
             | UntypedExpr::BinOp { .. }
             | UntypedExpr::PipeLine { .. }
             | UntypedExpr::Case { .. }
+            | UntypedExpr::If { .. }
+            | UntypedExpr::SubjectlessCase { .. }
             | UntypedExpr::FieldAccess { .. }
             | UntypedExpr::Tuple { .. }
             | UntypedExpr::TupleIndex { .. }
@@ -3271,6 +3280,27 @@ pub enum AssignmentKind<Expression> {
         /// ```
         message: Option<Expression>,
     },
+    /// let x = ... else { ... }
+    ///
+    /// This only exists in the untyped AST: during analysis it is desugared
+    /// into a case expression wrapping all the statements that follow it.
+    LetElse {
+        /// The src byte span of the `else` keyword
+        ///
+        /// ```gleam
+        /// let Ok(a) = something() else { panic }
+        ///                         ^^^^
+        /// ```
+        else_location: SrcSpan,
+
+        /// The block evaluated if the pattern doesn't match, it must diverge:
+        ///
+        /// ```gleam
+        /// let Ok(a) = something() else { panic }
+        ///                              ^^^^^^^^^
+        /// ```
+        body: Box<Expression>,
+    },
 }
 
 impl<Expression> AssignmentKind<Expression> {
@@ -3281,7 +3311,7 @@ impl<Expression> AssignmentKind<Expression> {
     pub fn is_assert(&self) -> bool {
         match self {
             Self::Assert { .. } => true,
-            Self::Let | Self::Generated => false,
+            Self::Let | Self::Generated | Self::LetElse { .. } => false,
         }
     }
 }
@@ -79,6 +79,16 @@ pub enum Constant<T, RecordTag> {
         right: Box<Self>,
     },
 
+    /// Arithmetic on Int or Float constants, like `4 * 1024`. This is folded
+    /// into a literal during analysis so it never reaches code generation.
+    BinOp {
+        location: SrcSpan,
+        name: BinOp,
+        name_location: SrcSpan,
+        left: Box<Self>,
+        right: Box<Self>,
+    },
+
     /// A placeholder constant used to allow module analysis to continue
     /// even when there are type errors. Should never end up in generated code.
     Invalid {
@@ -98,6 +108,8 @@ impl TypedConstant {
             Constant::Float { .. } => type_::float(),
             Constant::String { .. } | Constant::StringConcatenation { .. } => type_::string(),
             Constant::BitArray { .. } => type_::bit_array(),
+            Constant::BinOp { name, .. } if name.is_float_operator() => type_::float(),
+            Constant::BinOp { .. } => type_::int(),
 
             Constant::List { type_, .. }
             | Constant::Tuple { type_, .. }
@@ -141,7 +153,8 @@ impl TypedConstant {
                 .iter()
                 .find_map(|segment| segment.find_node(byte_index))
                 .unwrap_or(Located::Constant(self)),
-            Constant::StringConcatenation { left, right, .. } => left
+            Constant::StringConcatenation { left, right, .. }
+            | Constant::BinOp { left, right, .. } => left
                 .find_node(byte_index)
                 .or_else(|| right.find_node(byte_index))
                 .unwrap_or(Located::Constant(self)),
@@ -157,6 +170,7 @@ impl TypedConstant {
             | Constant::List { .. }
             | Constant::BitArray { .. }
             | Constant::StringConcatenation { .. }
+            | Constant::BinOp { .. }
             | Constant::Invalid { .. } => None,
             Constant::Record {
                 record_constructor: value_constructor,
@@ -211,7 +225,8 @@ impl TypedConstant {
                 })
                 .fold(im::hashset![], im::HashSet::union),
 
-            Constant::StringConcatenation { left, right, .. } => left
+            Constant::StringConcatenation { left, right, .. }
+            | Constant::BinOp { left, right, .. } => left
                 .referenced_variables()
                 .union(right.referenced_variables()),
         }
@@ -355,6 +370,23 @@ impl TypedConstant {
             ) => left.syntactically_eq(other_left) && right.syntactically_eq(other_right),
             (Constant::StringConcatenation { .. }, _) => false,
 
+            (
+                Constant::BinOp {
+                    name, left, right, ..
+                },
+                Constant::BinOp {
+                    name: other_name,
+                    left: other_left,
+                    right: other_right,
+                    ..
+                },
+            ) => {
+                name == other_name
+                    && left.syntactically_eq(other_left)
+                    && right.syntactically_eq(other_right)
+            }
+            (Constant::BinOp { .. }, _) => false,
+
             (Constant::Invalid { .. }, _) => false,
         }
     }
@@ -379,7 +411,8 @@ impl<A, B> Constant<A, B> {
             | Constant::BitArray { location, .. }
             | Constant::Var { location, .. }
             | Constant::Invalid { location, .. }
-            | Constant::StringConcatenation { location, .. } => *location,
+            | Constant::StringConcatenation { location, .. }
+            | Constant::BinOp { location, .. } => *location,
         }
     }
 
@@ -397,6 +430,7 @@ impl<A, B> Constant<A, B> {
             | Constant::RecordUpdate { .. }
             | Constant::BitArray { .. }
             | Constant::StringConcatenation { .. }
+            | Constant::BinOp { .. }
             | Constant::Invalid { .. } => false,
         }
     }
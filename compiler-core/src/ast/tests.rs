@@ -658,6 +658,7 @@ fn find_node_module_select() {
             documentation: None,
             field_map: None,
             purity: Purity::Pure,
+            must_use: false,
         },
     };
 
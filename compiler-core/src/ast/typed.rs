@@ -838,6 +838,51 @@ impl TypedExpr {
         }
     }
 
+    /// Returns true if this is a call to a function with the `@must_use`
+    /// attribute, either on its own or as the final step of a pipeline.
+    pub fn is_must_use_call(&self) -> bool {
+        match self {
+            Self::Call { fun, .. } => matches!(
+                fun.as_ref(),
+                Self::Var {
+                    constructor: ValueConstructor {
+                        variant: ValueConstructorVariant::ModuleFn { must_use: true, .. },
+                        ..
+                    },
+                    ..
+                } | Self::ModuleSelect {
+                    constructor: ModuleValueConstructor::Fn { must_use: true, .. },
+                    ..
+                }
+            ),
+
+            Self::Pipeline { finally, .. } => finally.is_must_use_call(),
+
+            Self::Int { .. }
+            | Self::Float { .. }
+            | Self::String { .. }
+            | Self::Block { .. }
+            | Self::Var { .. }
+            | Self::Fn { .. }
+            | Self::List { .. }
+            | Self::BinOp { .. }
+            | Self::Case { .. }
+            | Self::RecordAccess { .. }
+            | Self::PositionalAccess { .. }
+            | Self::ModuleSelect { .. }
+            | Self::Tuple { .. }
+            | Self::TupleIndex { .. }
+            | Self::Todo { .. }
+            | Self::Panic { .. }
+            | Self::Echo { .. }
+            | Self::BitArray { .. }
+            | Self::RecordUpdate { .. }
+            | Self::NegateBool { .. }
+            | Self::NegateInt { .. }
+            | Self::Invalid { .. } => false,
+        }
+    }
+
     pub fn is_known_bool(&self) -> bool {
         match self {
             TypedExpr::BinOp {
@@ -1644,6 +1689,19 @@ impl TypedExpr {
     pub fn is_todo_with_no_message(&self) -> bool {
         matches!(self, TypedExpr::Todo { message: None, .. })
     }
+
+    /// Returns true if the expression is a `todo`, or a block whose last
+    /// expression is a `todo`.
+    ///
+    pub fn ends_with_todo(&self) -> bool {
+        if let TypedExpr::Block { statements, .. } = self
+            && let Statement::Expression(last) = statements.last()
+        {
+            last.ends_with_todo()
+        } else {
+            matches!(self, TypedExpr::Todo { .. })
+        }
+    }
 }
 
 /// Checks that two slices have the same number of item and that the given
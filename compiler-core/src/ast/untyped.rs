@@ -23,6 +23,14 @@ pub enum UntypedExpr {
         value: EcoString,
     },
 
+    /// A string with `\{...}` interpolations in it, such as
+    /// `"\{name} not found"`. It is turned into a `<>` concatenation of its
+    /// segments during analysis.
+    InterpolatedString {
+        location: SrcSpan,
+        segments: Vec<InterpolatedStringSegment>,
+    },
+
     Block {
         location: SrcSpan,
         statements: Vec1<UntypedStatement>,
@@ -77,6 +85,33 @@ pub enum UntypedExpr {
         clauses: Option<Vec<Clause<Self, (), ()>>>,
     },
 
+    If {
+        location: SrcSpan,
+        condition: Box<Self>,
+        /// Always a `Block`.
+        then: Box<Self>,
+        /// Either a `Block` or, for `else if`, another `If`.
+        /// None if the `else` branch was not written.
+        otherwise: Option<Box<Self>>,
+    },
+
+    /// A `case` with no subjects, where each clause is guarded by a `Bool`
+    /// condition rather than a pattern:
+    ///
+    /// ```gleam
+    /// case {
+    ///   x < 0 -> "negative"
+    ///   x > 0 -> "positive"
+    ///   _ -> "zero"
+    /// }
+    /// ```
+    ///
+    /// It is turned into nested `case`s on `Bool` values during analysis.
+    SubjectlessCase {
+        location: SrcSpan,
+        clauses: Vec<SubjectlessClause>,
+    },
+
     FieldAccess {
         // This is the location of the whole record and field
         //   user.name
@@ -161,6 +196,8 @@ impl UntypedExpr {
             | Self::Todo { location, .. }
             | Self::Echo { location, .. }
             | Self::Case { location, .. }
+            | Self::If { location, .. }
+            | Self::SubjectlessCase { location, .. }
             | Self::Call { location, .. }
             | Self::List { location, .. }
             | Self::Float { location, .. }
@@ -169,6 +206,7 @@ impl UntypedExpr {
             | Self::Tuple { location, .. }
             | Self::Panic { location, .. }
             | Self::String { location, .. }
+            | Self::InterpolatedString { location, .. }
             | Self::BitArray { location, .. }
             | Self::NegateInt { location, .. }
             | Self::NegateBool { location, .. }
@@ -185,12 +223,15 @@ impl UntypedExpr {
             Self::Int { .. }
             | Self::Float { .. }
             | Self::String { .. }
+            | Self::InterpolatedString { .. }
             | Self::Var { .. }
             | Self::Fn { .. }
             | Self::List { .. }
             | Self::Call { .. }
             | Self::BinOp { .. }
             | Self::Case { .. }
+            | Self::If { .. }
+            | Self::SubjectlessCase { .. }
             | Self::FieldAccess { .. }
             | Self::Tuple { .. }
             | Self::TupleIndex { .. }
@@ -211,12 +252,15 @@ impl UntypedExpr {
             Self::Int { .. }
             | Self::Float { .. }
             | Self::String { .. }
+            | Self::InterpolatedString { .. }
             | Self::Block { .. }
             | Self::Var { .. }
             | Self::Fn { .. }
             | Self::List { .. }
             | Self::Call { .. }
             | Self::Case { .. }
+            | Self::If { .. }
+            | Self::SubjectlessCase { .. }
             | Self::FieldAccess { .. }
             | Self::Tuple { .. }
             | Self::TupleIndex { .. }
@@ -243,6 +287,7 @@ impl UntypedExpr {
             UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
             | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Var { .. } => true,
 
             UntypedExpr::NegateBool { value, .. }
@@ -258,6 +303,8 @@ impl UntypedExpr {
             | UntypedExpr::BinOp { .. }
             | UntypedExpr::PipeLine { .. }
             | UntypedExpr::Case { .. }
+            | UntypedExpr::If { .. }
+            | UntypedExpr::SubjectlessCase { .. }
             | UntypedExpr::Tuple { .. }
             | UntypedExpr::TupleIndex { .. }
             | UntypedExpr::Todo { .. }
@@ -301,6 +348,27 @@ impl UntypedExpr {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterpolatedStringSegment {
+    /// Text written as it is in the source, escape sequences included.
+    Literal { location: SrcSpan, value: EcoString },
+    /// An expression written between `\{` and `}`, the location spans both
+    /// delimiters.
+    Expression {
+        location: SrcSpan,
+        expression: UntypedExpr,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectlessClause {
+    pub location: SrcSpan,
+    /// The `Bool` expression on the left of the `->`. This is `None` for the
+    /// `_` catch-all clause.
+    pub condition: Option<UntypedExpr>,
+    pub then: UntypedExpr,
+}
+
 impl HasLocation for UntypedExpr {
     fn location(&self) -> SrcSpan {
         self.location()
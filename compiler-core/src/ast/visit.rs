@@ -740,6 +740,17 @@ pub trait Visit<'ast> {
         visit_typed_constant_string_concatenation(self, location, left, right);
     }
 
+    fn visit_typed_constant_binop(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast BinOp,
+        name_location: &'ast SrcSpan,
+        left: &'ast TypedConstant,
+        right: &'ast TypedConstant,
+    ) {
+        visit_typed_constant_binop(self, location, name, name_location, left, right);
+    }
+
     fn visit_typed_constant_invalid(
         &mut self,
         location: &'ast SrcSpan,
@@ -769,6 +780,18 @@ fn visit_typed_constant_string_concatenation<'a, V: Visit<'a> + ?Sized>(
     v.visit_typed_constant(right);
 }
 
+fn visit_typed_constant_binop<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    _location: &'a SrcSpan,
+    _name: &'a BinOp,
+    _name_location: &'a SrcSpan,
+    left: &'a TypedConstant,
+    right: &'a TypedConstant,
+) {
+    v.visit_typed_constant(left);
+    v.visit_typed_constant(right);
+}
+
 pub fn visit_typed_constant_var<'a, V: Visit<'a> + ?Sized>(
     _v: &mut V,
     _location: &'a SrcSpan,
@@ -1088,6 +1111,13 @@ pub fn visit_typed_constant<'a, V: Visit<'a> + ?Sized>(v: &mut V, constant: &'a
             left,
             right,
         } => v.visit_typed_constant_string_concatenation(location, left, right),
+        super::Constant::BinOp {
+            location,
+            name,
+            name_location,
+            left,
+            right,
+        } => v.visit_typed_constant_binop(location, name, name_location, left, right),
         super::Constant::Invalid {
             location,
             type_,
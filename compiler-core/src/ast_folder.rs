@@ -7,14 +7,15 @@ use crate::{
     analyse::Inferred,
     ast::{
         Assert, AssignName, Assignment, BinOp, BitArraySize, CallArg, Constant, Definition,
-        FunctionLiteralKind, InvalidExpression, Pattern, RecordBeingUpdated, RecordUpdateArg,
-        SrcSpan, Statement, TailPattern, TargetedDefinition, TodoKind, TypeAst, TypeAstConstructor,
-        TypeAstFn, TypeAstHole, TypeAstTuple, TypeAstVar, UntypedArg, UntypedAssert,
-        UntypedAssignment, UntypedClause, UntypedConstant, UntypedConstantBitArraySegment,
-        UntypedCustomType, UntypedDefinition, UntypedExpr, UntypedExprBitArraySegment,
-        UntypedFunction, UntypedImport, UntypedModule, UntypedModuleConstant, UntypedPattern,
-        UntypedPatternBitArraySegment, UntypedRecordUpdateArg, UntypedStatement,
-        UntypedTailPattern, UntypedTypeAlias, UntypedUse, UntypedUseAssignment, Use, UseAssignment,
+        FunctionLiteralKind, InterpolatedStringSegment, InvalidExpression, Pattern,
+        RecordBeingUpdated, RecordUpdateArg, SrcSpan, Statement, SubjectlessClause, TailPattern,
+        TargetedDefinition, TodoKind, TypeAst, TypeAstConstructor, TypeAstFn, TypeAstHole,
+        TypeAstTuple, TypeAstVar, UntypedArg, UntypedAssert, UntypedAssignment, UntypedClause,
+        UntypedConstant, UntypedConstantBitArraySegment, UntypedCustomType, UntypedDefinition,
+        UntypedExpr, UntypedExprBitArraySegment, UntypedFunction, UntypedImport, UntypedModule,
+        UntypedModuleConstant, UntypedPattern, UntypedPatternBitArraySegment,
+        UntypedRecordUpdateArg, UntypedStatement, UntypedTailPattern, UntypedTypeAlias, UntypedUse,
+        UntypedUseAssignment, Use, UseAssignment,
     },
     build::Target,
     parse::LiteralFloatValue,
@@ -266,6 +267,10 @@ pub trait UntypedExprFolder: TypeAstFolder + UntypedConstantFolder + PatternFold
             } => self.fold_float(location, value, float_value),
             UntypedExpr::String { location, value } => self.fold_string(location, value),
 
+            UntypedExpr::InterpolatedString { location, segments } => {
+                self.fold_interpolated_string(location, segments)
+            }
+
             UntypedExpr::Block {
                 location,
                 statements,
@@ -315,6 +320,17 @@ pub trait UntypedExprFolder: TypeAstFolder + UntypedConstantFolder + PatternFold
                 clauses,
             } => self.fold_case(location, subjects, clauses),
 
+            UntypedExpr::If {
+                location,
+                condition,
+                then,
+                otherwise,
+            } => self.fold_if(location, condition, then, otherwise),
+
+            UntypedExpr::SubjectlessCase { location, clauses } => {
+                self.fold_subjectless_case(location, clauses)
+            }
+
             UntypedExpr::FieldAccess {
                 location,
                 label_location,
@@ -370,6 +386,23 @@ pub trait UntypedExprFolder: TypeAstFolder + UntypedConstantFolder + PatternFold
             | UntypedExpr::NegateInt { .. }
             | UntypedExpr::NegateBool { .. } => expression,
 
+            UntypedExpr::InterpolatedString { location, segments } => {
+                let segments = segments
+                    .into_iter()
+                    .map(|segment| match segment {
+                        InterpolatedStringSegment::Literal { .. } => segment,
+                        InterpolatedStringSegment::Expression {
+                            location,
+                            expression,
+                        } => InterpolatedStringSegment::Expression {
+                            location,
+                            expression: self.fold_expr(expression),
+                        },
+                    })
+                    .collect();
+                UntypedExpr::InterpolatedString { location, segments }
+            }
+
             UntypedExpr::Todo {
                 kind,
                 location,
@@ -521,6 +554,35 @@ pub trait UntypedExprFolder: TypeAstFolder + UntypedConstantFolder + PatternFold
                 }
             }
 
+            UntypedExpr::If {
+                location,
+                condition,
+                then,
+                otherwise,
+            } => {
+                let condition = Box::new(self.fold_expr(*condition));
+                let then = Box::new(self.fold_expr(*then));
+                let otherwise = otherwise.map(|e| Box::new(self.fold_expr(*e)));
+                UntypedExpr::If {
+                    location,
+                    condition,
+                    then,
+                    otherwise,
+                }
+            }
+
+            UntypedExpr::SubjectlessCase { location, clauses } => {
+                let clauses = clauses
+                    .into_iter()
+                    .map(|clause| SubjectlessClause {
+                        location: clause.location,
+                        condition: clause.condition.map(|c| self.fold_expr(c)),
+                        then: self.fold_expr(clause.then),
+                    })
+                    .collect();
+                UntypedExpr::SubjectlessCase { location, clauses }
+            }
+
             UntypedExpr::FieldAccess {
                 location,
                 label_location,
@@ -736,6 +798,14 @@ pub trait UntypedExprFolder: TypeAstFolder + UntypedConstantFolder + PatternFold
         UntypedExpr::String { location, value }
     }
 
+    fn fold_interpolated_string(
+        &mut self,
+        location: SrcSpan,
+        segments: Vec<InterpolatedStringSegment>,
+    ) -> UntypedExpr {
+        UntypedExpr::InterpolatedString { location, segments }
+    }
+
     fn fold_block(&mut self, location: SrcSpan, statements: Vec1<UntypedStatement>) -> UntypedExpr {
         UntypedExpr::Block {
             location,
@@ -826,6 +896,29 @@ pub trait UntypedExprFolder: TypeAstFolder + UntypedConstantFolder + PatternFold
         }
     }
 
+    fn fold_if(
+        &mut self,
+        location: SrcSpan,
+        condition: Box<UntypedExpr>,
+        then: Box<UntypedExpr>,
+        otherwise: Option<Box<UntypedExpr>>,
+    ) -> UntypedExpr {
+        UntypedExpr::If {
+            location,
+            condition,
+            then,
+            otherwise,
+        }
+    }
+
+    fn fold_subjectless_case(
+        &mut self,
+        location: SrcSpan,
+        clauses: Vec<SubjectlessClause>,
+    ) -> UntypedExpr {
+        UntypedExpr::SubjectlessCase { location, clauses }
+    }
+
     fn fold_field_access(
         &mut self,
         location: SrcSpan,
@@ -1019,6 +1112,14 @@ pub trait UntypedConstantFolder {
                 right,
             } => self.fold_constant_string_concatenation(location, left, right),
 
+            Constant::BinOp {
+                location,
+                name,
+                name_location,
+                left,
+                right,
+            } => self.fold_constant_binop(location, name, name_location, left, right),
+
             Constant::Invalid {
                 location,
                 type_: (),
@@ -1158,6 +1259,23 @@ pub trait UntypedConstantFolder {
         }
     }
 
+    fn fold_constant_binop(
+        &mut self,
+        location: SrcSpan,
+        name: BinOp,
+        name_location: SrcSpan,
+        left: Box<UntypedConstant>,
+        right: Box<UntypedConstant>,
+    ) -> UntypedConstant {
+        Constant::BinOp {
+            location,
+            name,
+            name_location,
+            left,
+            right,
+        }
+    }
+
     fn fold_constant_invalid(
         &mut self,
         location: SrcSpan,
@@ -1285,6 +1403,24 @@ pub trait UntypedConstantFolder {
                     right,
                 }
             }
+
+            Constant::BinOp {
+                location,
+                name,
+                name_location,
+                left,
+                right,
+            } => {
+                let left = Box::new(self.fold_constant(*left));
+                let right = Box::new(self.fold_constant(*right));
+                Constant::BinOp {
+                    location,
+                    name,
+                    name_location,
+                    left,
+                    right,
+                }
+            }
         }
     }
 }
@@ -150,13 +150,12 @@ where
 {
     let code: EcoString = io.read(&path)?.into();
 
-    let parsed = crate::parse::parse_module(path.clone(), &code, &emitter).map_err(|error| {
-        Error::Parse {
-            path: path.clone(),
-            src: code.clone(),
-            error: Box::new(error),
-        }
-    })?;
+    // A module with syntax errors is still loaded, so that the definitions the
+    // parser could recover can be analysed and used by the language server.
+    let (parsed, parse_errors) = match crate::parse::parse_module(path.clone(), &code, &emitter) {
+        Ok(parsed) => (parsed, vec![]),
+        Err(error) => (*error.parsed, error.errors.into_vec()),
+    };
     let mut ast = parsed.module;
     let extra = parsed.extra;
     let dependencies = ast.dependencies(target);
@@ -172,6 +171,7 @@ where
         name,
         code,
         ast,
+        parse_errors,
     };
     Ok(module)
 }
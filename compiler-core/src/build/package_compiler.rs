@@ -18,7 +18,7 @@ use crate::{
     dep_tree, error,
     io::{BeamCompiler, CommandExecutor, FileSystemReader, FileSystemWriter, Stdio},
     metadata::ModuleEncoder,
-    parse::extra::ModuleExtra,
+    parse::{error::ParseError, extra::ModuleExtra},
     paths, type_,
     uid::UniqueIdGenerator,
     warning::{TypeWarningEmitter, WarningEmitter},
@@ -537,18 +537,27 @@ fn analyse(
         package,
         dependencies,
         extra,
+        parse_errors,
     } in parsed_modules
     {
         tracing::debug!(module = ?name, "Type checking");
 
         let line_numbers = LineNumbers::new(&code);
+        let parse_errors = Vec1::try_from_vec(parse_errors).ok();
+
+        // The warnings for a module with syntax errors would mostly be caused
+        // by the code the parser couldn't make sense of, so they are dropped.
+        let module_warnings = match parse_errors {
+            Some(_) => WarningEmitter::null(),
+            None => warnings.clone(),
+        };
 
         let analysis = crate::analyse::ModuleAnalyzerConstructor {
             target,
             ids,
             origin,
             importable_modules: module_types,
-            warnings: &TypeWarningEmitter::new(path.clone(), code.clone(), warnings.clone()),
+            warnings: &TypeWarningEmitter::new(path.clone(), code.clone(), module_warnings),
             direct_dependencies: &direct_dependencies,
             dev_dependencies: &dev_dependencies,
             target_support,
@@ -556,6 +565,35 @@ fn analyse(
         }
         .infer_module(ast, line_numbers, path.clone());
 
+        // A module with syntax errors is analysed so that the language server
+        // can use the definitions the parser recovered, but the syntax errors
+        // are reported instead of any type error they may have caused.
+        if let Some(errors) = parse_errors {
+            let error = Error::Parse {
+                path: path.clone(),
+                src: code.clone(),
+                errors,
+            };
+            let ast = match analysis {
+                Outcome::Ok(ast) | Outcome::PartialFailure(ast, _) => ast,
+                Outcome::TotalFailure(_) => return Outcome::TotalFailure(error),
+            };
+            // Mark as incomplete so that this module isn't reloaded from cache.
+            let _ = incomplete_modules.insert(name.clone());
+            modules.push(Module {
+                dependencies,
+                origin,
+                extra,
+                mtime,
+                name,
+                code,
+                ast,
+                input_path: path,
+            });
+            // WARNING: This cannot be used for code generation as the code has errors.
+            return Outcome::PartialFailure(modules, error);
+        }
+
         match analysis {
             Outcome::Ok(ast) => {
                 // Module has compiled successfully. Make sure it isn't marked as incomplete.
@@ -738,6 +776,9 @@ pub(crate) struct UncompiledModule {
     pub dependencies: Vec<(EcoString, SrcSpan)>,
     pub ast: UntypedModule,
     pub extra: ModuleExtra,
+    /// The errors found while parsing the module. If there are any, `ast` only
+    /// holds the definitions the parser could recover.
+    pub parse_errors: Vec<ParseError>,
 }
 
 #[derive(Template)]
@@ -7,9 +7,9 @@ mod into_dependency_order_tests;
 use crate::{
     Result,
     ast::{
-        AssignName, AssignmentKind, BitArrayOption, BitArraySize, ClauseGuard, Constant, Pattern,
-        SrcSpan, Statement, UntypedClauseGuard, UntypedExpr, UntypedFunction,
-        UntypedModuleConstant, UntypedPattern, UntypedStatement,
+        AssignName, AssignmentKind, BitArrayOption, BitArraySize, ClauseGuard, Constant,
+        InterpolatedStringSegment, Pattern, SrcSpan, Statement, UntypedClauseGuard, UntypedExpr,
+        UntypedFunction, UntypedModuleConstant, UntypedPattern, UntypedStatement,
     },
     type_::Error,
 };
@@ -151,6 +151,11 @@ impl<'a> CallGraphBuilder<'a> {
             }
             Statement::Assignment(assignment) => {
                 self.expression(&assignment.value);
+                // The `else` body can't reference the variables bound by the
+                // pattern, so it is visited before them.
+                if let AssignmentKind::LetElse { body, .. } = &assignment.kind {
+                    self.expression(body);
+                }
                 self.pattern(&assignment.pattern);
                 match &assignment.kind {
                     AssignmentKind::Assert {
@@ -159,6 +164,7 @@ impl<'a> CallGraphBuilder<'a> {
                     } => self.expression(message),
                     AssignmentKind::Let
                     | AssignmentKind::Generated
+                    | AssignmentKind::LetElse { .. }
                     | AssignmentKind::Assert { message: None, .. } => {}
                 }
             }
@@ -181,6 +187,14 @@ impl<'a> CallGraphBuilder<'a> {
         match expression {
             UntypedExpr::Int { .. } | UntypedExpr::Float { .. } | UntypedExpr::String { .. } => (),
 
+            UntypedExpr::InterpolatedString { segments, .. } => {
+                for segment in segments {
+                    if let InterpolatedStringSegment::Expression { expression, .. } = segment {
+                        self.expression(expression);
+                    }
+                }
+            }
+
             UntypedExpr::Todo { message, .. } => {
                 if let Some(msg_expr) = message {
                     self.expression(msg_expr)
@@ -318,6 +332,28 @@ impl<'a> CallGraphBuilder<'a> {
                     self.names = names;
                 }
             }
+
+            UntypedExpr::If {
+                condition,
+                then,
+                otherwise,
+                ..
+            } => {
+                self.expression(condition);
+                self.expression(then);
+                if let Some(otherwise) = otherwise {
+                    self.expression(otherwise);
+                }
+            }
+
+            UntypedExpr::SubjectlessCase { clauses, .. } => {
+                for clause in clauses {
+                    if let Some(condition) = &clause.condition {
+                        self.expression(condition);
+                    }
+                    self.expression(&clause.then);
+                }
+            }
         }
     }
 
@@ -497,7 +533,8 @@ impl<'a> CallGraphBuilder<'a> {
                 }
             }
 
-            Constant::StringConcatenation { left, right, .. } => {
+            Constant::StringConcatenation { left, right, .. }
+            | Constant::BinOp { left, right, .. } => {
                 self.constant(left);
                 self.constant(right);
             }
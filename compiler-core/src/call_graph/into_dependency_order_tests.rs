@@ -52,6 +52,7 @@ fn parse_and_order(
                 can_run_on_javascript: true,
             },
             purity: Purity::Impure,
+            must_use: false,
         })
         .collect_vec();
     let constants = constants
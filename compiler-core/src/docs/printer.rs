@@ -115,15 +115,13 @@ impl Printer<'_> {
                     .raw(|this| this.custom_type(name, parameters, constructors, *opaque)),
                 documentation: markdown_documentation(documentation),
                 text_documentation: text_documentation(documentation),
-                deprecation_message: match deprecation {
-                    Deprecation::NotDeprecated => "".to_string(),
-                    Deprecation::Deprecated { message } => message.to_string(),
-                },
+                deprecation_message: deprecation_message(deprecation),
                 constructors: if *opaque {
                     Vec::new()
                 } else {
                     constructors
                         .iter()
+                        .filter(|constructor| !constructor.publicity.is_internal())
                         .map(|constructor| TypeConstructor {
                             definition: print(self.record_constructor(constructor)),
                             raw_definition: self.raw(|this| this.record_constructor(constructor)),
@@ -170,10 +168,7 @@ impl Printer<'_> {
                 text_documentation: text_documentation(documentation),
                 constructors: vec![],
                 source_url: source_links.url(*location),
-                deprecation_message: match deprecation {
-                    Deprecation::NotDeprecated => "".to_string(),
-                    Deprecation::Deprecated { message } => message.to_string(),
-                },
+                deprecation_message: deprecation_message(deprecation),
                 opaque: false,
             })
         }
@@ -236,10 +231,7 @@ impl Printer<'_> {
                 documentation: markdown_documentation(documentation),
                 text_documentation: text_documentation(documentation),
                 source_url: source_links.url(*location),
-                deprecation_message: match deprecation {
-                    Deprecation::NotDeprecated => "".to_string(),
-                    Deprecation::Deprecated { message } => message.to_string(),
-                },
+                deprecation_message: deprecation_message(deprecation),
             })
         }
 
@@ -264,10 +256,7 @@ impl Printer<'_> {
                 documentation: markdown_documentation(documentation),
                 text_documentation: text_documentation(documentation),
                 source_url: source_links.url(*location),
-                deprecation_message: match deprecation {
-                    Deprecation::NotDeprecated => "".to_string(),
-                    Deprecation::Deprecated { message } => message.to_string(),
-                },
+                deprecation_message: deprecation_message(deprecation),
             })
         }
 
@@ -300,12 +289,14 @@ impl Printer<'_> {
 
         let type_head = docvec![self.keyword(keywords), self.title(name), arguments];
 
-        if constructors.is_empty() || opaque {
+        if opaque {
             return type_head;
         }
 
+        // Variants marked as `@internal` are not part of the documentation.
         let constructors = constructors
             .iter()
+            .filter(|constructor| !constructor.publicity.is_internal())
             .map(|constructor| {
                 line()
                     .append(self.record_constructor(constructor))
@@ -313,6 +304,10 @@ impl Printer<'_> {
             })
             .collect_vec();
 
+        if constructors.is_empty() {
+            return type_head;
+        }
+
         docvec![type_head, " {", constructors, line(), "}"]
     }
 
@@ -770,3 +765,17 @@ const INDENT: isize = 2;
 fn print(doc: Document<'_>) -> String {
     doc.to_pretty_string(MAX_COLUMNS)
 }
+
+fn deprecation_message(deprecation: &Deprecation) -> String {
+    match deprecation {
+        Deprecation::NotDeprecated => "".to_string(),
+        Deprecation::Deprecated {
+            message,
+            since: None,
+        } => message.to_string(),
+        Deprecation::Deprecated {
+            message,
+            since: Some(since),
+        } => format!("{message} (since {since})"),
+    }
+}
@@ -780,6 +780,7 @@ fn const_string_concatenate_argument<'a>(
         | Constant::RecordUpdate { .. }
         | Constant::BitArray { .. }
         | Constant::Var { .. }
+        | Constant::BinOp { .. }
         | Constant::Invalid { .. } => const_inline(value, env),
     }
 }
@@ -882,6 +883,7 @@ fn const_segment<'a>(
             | Constant::RecordUpdate { .. }
             | Constant::Var { .. }
             | Constant::StringConcatenation { .. }
+            | Constant::BinOp { .. }
             | Constant::Invalid { .. } => const_inline(value, env).surround("(", ")"),
         }
     };
@@ -1592,6 +1594,7 @@ fn const_inline<'a>(literal: &'a TypedConstant, env: &mut Env<'a>) -> Document<'
         }
 
         Constant::RecordUpdate { .. } => panic!("record updates should not reach code generation"),
+        Constant::BinOp { .. } => panic!("constant arithmetic should not reach code generation"),
         Constant::Invalid { .. } => panic!("invalid constants should not reach code generation"),
     }
 }
@@ -1829,6 +1832,7 @@ fn clause_guard_string_concatenate_argument<'a>(
             | Constant::RecordUpdate { .. }
             | Constant::BitArray { .. }
             | Constant::Var { .. }
+            | Constant::BinOp { .. }
             | Constant::Invalid { .. } => docvec!["(", const_inline(literal, env), ")/binary"],
         },
 
@@ -2462,6 +2466,9 @@ fn assignment<'a>(
             position,
             *location,
         ),
+        AssignmentKind::LetElse { .. } => {
+            panic!("let else assignments should not reach code generation")
+        }
     }
 }
 
@@ -3356,6 +3363,9 @@ fn find_referenced_private_functions(
         Constant::RecordUpdate { .. } => {
             panic!("record updates should not reach code generation")
         }
+        Constant::BinOp { .. } => {
+            panic!("constant arithmetic should not reach code generation")
+        }
 
         Constant::Int { .. }
         | Constant::Float { .. }
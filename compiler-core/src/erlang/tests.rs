@@ -27,8 +27,10 @@ mod echo;
 mod external_fn;
 mod functions;
 mod guards;
+mod if_;
 mod inlining;
 mod let_assert;
+mod let_else;
 mod numbers;
 mod panic;
 mod patterns;
@@ -36,6 +38,7 @@ mod pipes;
 mod records;
 mod reserved;
 mod strings;
+mod subjectless_case;
 mod todo;
 mod type_params;
 mod use_;
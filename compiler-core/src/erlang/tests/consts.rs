@@ -206,3 +206,33 @@ fn use_private_in_tuple() {
         "#
     )
 }
+
+#[test]
+fn const_arithmetic_is_folded() {
+    assert_erl!(
+        r#"
+const kib = 1024
+
+const size = 4 * kib + 2
+
+const ratio = 1.5 *. 2.0
+
+pub fn main() {
+  #(size, ratio)
+}"#
+    );
+}
+
+#[test]
+fn const_arithmetic_truncates_negative_division() {
+    assert_erl!(
+        r#"
+const quotient = -7 / 2
+
+const remainder = -7 % 2
+
+pub fn main() {
+  #(quotient, remainder)
+}"#
+    );
+}
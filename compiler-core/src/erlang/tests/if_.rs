@@ -0,0 +1,43 @@
+use crate::assert_erl;
+
+#[test]
+fn if_else() {
+    assert_erl!(
+        r#"
+pub fn main(x) {
+  if x > 0 { "positive" } else { "not positive" }
+}
+"#
+    );
+}
+
+#[test]
+fn if_else_if() {
+    assert_erl!(
+        r#"
+pub fn main(x) {
+  if x > 0 {
+    1
+  } else if x < 0 {
+    -1
+  } else {
+    0
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn if_without_else() {
+    assert_erl!(
+        r#"
+pub fn main(x) {
+  if x { wibble() }
+  1
+}
+
+fn wibble() { Nil }
+"#
+    );
+}
@@ -467,3 +467,169 @@ pub fn main() {
 "#
     );
 }
+
+#[test]
+fn const_record_label_shorthand() {
+    assert_erl!(
+        "
+pub type Person {
+  Person(name: String, age: Int)
+}
+
+pub const name = \"Alice\"
+pub const age = 30
+pub const shorthand = Person(name:, age:)
+pub const explicit = Person(name: name, age: age)
+
+pub fn main() {
+  #(shorthand, explicit)
+}
+",
+    );
+}
+
+#[test]
+fn const_record_update_label_shorthand() {
+    assert_erl!(
+        "
+pub type Person {
+  Person(name: String, age: Int)
+}
+
+pub const name = \"Bob\"
+pub const alice = Person(\"Alice\", 30)
+pub const shorthand = Person(..alice, name:)
+pub const explicit = Person(..alice, name: name)
+
+pub fn main() {
+  #(shorthand, explicit)
+}
+",
+    );
+}
+
+#[test]
+fn const_record_update_all_fields() {
+    assert_erl!(
+        "
+pub type Person {
+  Person(name: String, age: Int)
+}
+
+pub const alice = Person(\"Alice\", 30)
+pub const bob = Person(..alice, name: \"Bob\", age: 40)
+
+pub fn main() {
+  bob
+}
+",
+    );
+}
+
+#[test]
+fn const_record_update_chained() {
+    assert_erl!(
+        "
+pub type Person {
+  Person(name: String, age: Int, admin: Bool)
+}
+
+pub const alice = Person(\"Alice\", 30, False)
+pub const bob = Person(..alice, name: \"Bob\")
+pub const carol = Person(..bob, admin: True)
+
+pub fn main() {
+  carol
+}
+",
+    );
+}
+
+#[test]
+fn const_record_update_imported_record() {
+    assert_erl!(
+        (
+            "thepackage",
+            "other",
+            r#"
+pub type Wibble {
+  Wibble(a: String, b: String, c: fn() -> String)
+}
+
+const b = "b"
+
+pub fn wobble() -> String {
+  "wobble"
+}
+
+pub const wibble = Wibble("a", b, wobble)
+"#
+        ),
+        r#"
+import other
+
+pub const w = other.Wibble(..other.wibble, a: "w")
+
+pub fn main() {
+  w
+}
+"#,
+    );
+}
+
+#[test]
+fn const_record_update_imported_record_referencing_another_module() {
+    assert_erl!(
+        (
+            "thepackage",
+            "third",
+            r#"
+pub fn wobble() -> String {
+  "wobble"
+}
+
+pub fn wubble() -> String {
+  "wubble"
+}
+"#
+        ),
+        (
+            "thepackage",
+            "other",
+            r#"
+import third.{wobble as wub} as th
+
+pub type Wibble {
+  Wibble(a: String, b: fn() -> String, c: fn() -> String)
+}
+
+pub const wibble = Wibble("a", wub, th.wubble)
+"#
+        ),
+        r#"
+import other
+import third as t
+
+pub const w = other.Wibble(..other.wibble, a: "w")
+
+pub fn main() {
+  w
+}
+"#,
+    );
+}
+
+#[test]
+fn positional_field_access() {
+    assert_erl!(
+        "
+pub type Cat {
+  Cat(String, Int)
+}
+
+pub fn main(cat: Cat) {
+  #(cat.0, cat.1)
+}
+",
+    );
+}
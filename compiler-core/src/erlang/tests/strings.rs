@@ -492,3 +492,14 @@ pub fn main() {
 "#
     );
 }
+
+#[test]
+fn interpolated_string() {
+    assert_erl!(
+        r#"
+pub fn main(name) {
+  "user \{name} not found"
+}
+"#,
+    );
+}
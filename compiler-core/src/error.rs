@@ -65,7 +65,7 @@ pub enum Error {
     Parse {
         path: Utf8PathBuf,
         src: EcoString,
-        error: Box<crate::parse::error::ParseError>,
+        errors: Vec1<crate::parse::error::ParseError>,
     },
 
     #[error("type checking failed")]
@@ -1610,6 +1610,25 @@ a floating point type: float values must be in the range -1.7976931348623157e308
                         }),
                     },
 
+                    TypeError::ConstantDivisionByZero { location } => Diagnostic {
+                        title: "Division by zero in constant".into(),
+                        text: wrap(
+                            "Arithmetic in constants is evaluated at compile time, \
+and this constant divides by zero so it has no value.",
+                        ),
+                        hint: None,
+                        level: Level::Error,
+                        location: Some(Location {
+                            label: Label {
+                                text: None,
+                                span: *location,
+                            },
+                            path: path.clone(),
+                            src: src.clone(),
+                            extra_labels: vec![],
+                        }),
+                    },
+
                     TypeError::InvalidImport {
                         location,
                         importing_module,
@@ -2478,6 +2497,61 @@ a label or use a record constructor.",
                                 }),
                             }
                         }
+                        UnsafeRecordUpdateReason::PrivateValue { module, name } => {
+                            let text = wrap_format!(
+                                "This record is a constant from the `{module}` module \
+and it refers to `{name}`, which is private to that module. The record can't \
+be copied into this module, so it cannot be used in a constant record update."
+                            );
+
+                            Diagnostic {
+                                title: "Private value in record update".into(),
+                                text,
+                                hint: None,
+                                level: Level::Error,
+                                location: Some(Location {
+                                    label: Label {
+                                        text: Some(format!("This refers to `{module}.{name}`")),
+                                        span: *location,
+                                    },
+                                    path: path.clone(),
+                                    src: src.clone(),
+                                    extra_labels: vec![],
+                                }),
+                            }
+                        }
+                        UnsafeRecordUpdateReason::UnimportedModule {
+                            constant_module,
+                            value_module,
+                            name,
+                        } => {
+                            let text = wrap_format!(
+                                "This record is a constant from the `{constant_module}` \
+module and it refers to `{name}` from the `{value_module}` module, which is \
+not imported by this module. The record can't be copied into this module, \
+so it cannot be used in a constant record update."
+                            );
+
+                            Diagnostic {
+                                title: "Unimported module in record update".into(),
+                                text,
+                                hint: Some(format!(
+                                    "Import the `{value_module}` module to use this record."
+                                )),
+                                level: Level::Error,
+                                location: Some(Location {
+                                    label: Label {
+                                        text: Some(format!(
+                                            "This refers to `{value_module}.{name}`"
+                                        )),
+                                        span: *location,
+                                    },
+                                    path: path.clone(),
+                                    src: src.clone(),
+                                    extra_labels: vec![],
+                                }),
+                            }
+                        }
                     },
 
                     TypeError::UnknownType {
@@ -2957,6 +3031,35 @@ however it has this type:
                         }
                     }
 
+                    TypeError::InterpolatedValueNotString { location, given } => {
+                        let mut printer = Printer::new(names);
+                        let text = format!(
+                            "Only strings can be interpolated into a string, \
+however this value has this type:
+
+    {}",
+                            printer.print_type(given),
+                        );
+                        Diagnostic {
+                            title: "Type mismatch".into(),
+                            text,
+                            hint: Some(
+                                "Convert it to a string first, for example with `int.to_string`."
+                                    .into(),
+                            ),
+                            level: Level::Error,
+                            location: Some(Location {
+                                label: Label {
+                                    text: Some("This is not a string".into()),
+                                    span: *location,
+                                },
+                                path: path.clone(),
+                                src: src.clone(),
+                                extra_labels: vec![],
+                            }),
+                        }
+                    }
+
                     TypeError::NotATupleUnbound { location } => {
                         let text = wrap(
                             "To index into a tuple we need to \
@@ -2980,6 +3083,83 @@ Please add some type annotations so we can continue.",
                         }
                     }
 
+                    TypeError::OutOfBoundsPositionalField {
+                        location, size: 0, ..
+                    } => Diagnostic {
+                        title: "Out of bounds field index".into(),
+                        text: wrap(
+                            "This record has no unlabelled fields so it cannot be \
+indexed at all. Labelled fields are accessed using their label.",
+                        ),
+                        hint: None,
+                        level: Level::Error,
+                        location: Some(Location {
+                            label: Label {
+                                text: None,
+                                span: *location,
+                            },
+                            path: path.clone(),
+                            src: src.clone(),
+                            extra_labels: vec![],
+                        }),
+                    },
+
+                    TypeError::OutOfBoundsPositionalField {
+                        location,
+                        index,
+                        size,
+                    } => {
+                        let text = wrap_format!(
+                            "The index being accessed for this record is {}, but this \
+record has {} unlabelled fields so the highest valid index is {}.",
+                            index,
+                            size,
+                            size - 1,
+                        );
+                        Diagnostic {
+                            title: "Out of bounds field index".into(),
+                            text,
+                            hint: None,
+                            level: Level::Error,
+                            location: Some(Location {
+                                label: Label {
+                                    text: Some("This index is too large".into()),
+                                    span: *location,
+                                },
+                                path: path.clone(),
+                                src: src.clone(),
+                                extra_labels: vec![],
+                            }),
+                        }
+                    }
+
+                    TypeError::PositionalAccessUnknownVariant { location, type_ } => {
+                        let mut printer = Printer::new(names);
+                        let text = wrap_format!(
+                            "This value has type `{}`, which has multiple variants. \
+A field can only be accessed by position once it is known which variant the \
+value is.",
+                            printer.print_type(type_),
+                        );
+                        Diagnostic {
+                            title: "Unknown record variant".into(),
+                            text,
+                            hint: Some(
+                                "Use a `case` expression to match on the variant first.".into(),
+                            ),
+                            level: Level::Error,
+                            location: Some(Location {
+                                label: Label {
+                                    text: Some("Which variant is this?".into()),
+                                    span: *location,
+                                },
+                                path: path.clone(),
+                                src: src.clone(),
+                                extra_labels: vec![],
+                            }),
+                        }
+                    }
+
                     TypeError::RecordAccessUnknownType { location } => {
                         let text = wrap(
                             "In order to access a record field \
@@ -3486,6 +3666,29 @@ The missing patterns are:\n",
                         }
                     }
 
+                    TypeError::LetElseDoesNotDiverge { location } => {
+                        let text = wrap(
+                            "The else body of a `let else` is run when the pattern \
+doesn't match, so there are no values for the pattern's variables to take. It must \
+end by panicking, for example with `panic` or `todo`.",
+                        );
+                        Diagnostic {
+                            title: "Else body does not panic".into(),
+                            text,
+                            hint: None,
+                            level: Level::Error,
+                            location: Some(Location {
+                                src: src.clone(),
+                                path: path.to_path_buf(),
+                                label: Label {
+                                    text: Some("This can finish without panicking".into()),
+                                    span: *location,
+                                },
+                                extra_labels: Vec::new(),
+                            }),
+                        }
+                    }
+
                     TypeError::UnsupportedExpressionTarget {
                         location,
                         target: current_target,
@@ -4031,6 +4234,29 @@ and explain your usecase for this pattern, and how you would expect it to behave
                         }),
                     },
 
+                    TypeError::InternalVariantFromOtherPackage {
+                        location,
+                        name,
+                        package,
+                    } => Diagnostic {
+                        title: "Internal variant".to_string(),
+                        text: wrap_format!(
+                            "The `{name}` variant is internal to the `{package}` package, \
+so it can't be used to construct or match on values outside of it."
+                        ),
+                        hint: None,
+                        level: Level::Error,
+                        location: Some(Location {
+                            label: Label {
+                                text: Some("This variant is internal".to_string()),
+                                span: *location,
+                            },
+                            path: path.clone(),
+                            src: src.clone(),
+                            extra_labels: vec![],
+                        }),
+                    },
+
                     TypeError::SrcImportingDevDependency {
                         location,
                         importing_module,
@@ -4097,39 +4323,42 @@ with no constructors."
                 })
                 .collect_vec(),
 
-            Error::Parse { path, src, error } => {
-                let location = if error.error == ParseErrorType::UnexpectedEof {
-                    crate::ast::SrcSpan {
-                        start: (src.len() - 1) as u32,
-                        end: (src.len() - 1) as u32,
-                    }
-                } else {
-                    error.location
-                };
+            Error::Parse { path, src, errors } => errors
+                .iter()
+                .map(|error| {
+                    let location = if error.error == ParseErrorType::UnexpectedEof {
+                        crate::ast::SrcSpan {
+                            start: (src.len() - 1) as u32,
+                            end: (src.len() - 1) as u32,
+                        }
+                    } else {
+                        error.location
+                    };
 
-                let title = String::from("Syntax error");
-                let ParseErrorDetails {
-                    text,
-                    label_text,
-                    extra_labels,
-                    hint,
-                } = error.error.details();
-                vec![Diagnostic {
-                    title,
-                    text,
-                    level: Level::Error,
-                    location: Some(Location {
-                        src: src.clone(),
-                        path: path.clone(),
-                        label: Label {
-                            text: Some(label_text.into()),
-                            span: location,
-                        },
+                    let title = String::from("Syntax error");
+                    let ParseErrorDetails {
+                        text,
+                        label_text,
                         extra_labels,
-                    }),
-                    hint,
-                }]
-            }
+                        hint,
+                    } = error.error.details();
+                    Diagnostic {
+                        title,
+                        text,
+                        level: Level::Error,
+                        location: Some(Location {
+                            src: src.clone(),
+                            path: path.clone(),
+                            label: Label {
+                                text: Some(label_text.into()),
+                                span: location,
+                            },
+                            extra_labels,
+                        }),
+                        hint,
+                    }
+                })
+                .collect_vec(),
 
             Error::ImportCycle { modules } => {
                 let first_location = &modules.first().1;
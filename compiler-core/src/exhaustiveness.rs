@@ -3167,6 +3167,7 @@ impl ConstructorSpecialiser {
             name,
             parameters,
             documentation,
+            publicity,
         } = v;
         let parameters = parameters
             .iter()
@@ -3180,6 +3181,7 @@ impl ConstructorSpecialiser {
             name: name.clone(),
             parameters,
             documentation: documentation.clone(),
+            publicity: *publicity,
         }
     }
 
@@ -187,6 +187,16 @@ impl<'a, 'env> MissingPatternsGenerator<'a, 'env> {
                     .name
                     .clone();
 
+                // A variant marked as `@internal` can't be matched on outside
+                // of its own package, so we suggest a catch-all pattern instead.
+                if self
+                    .environment
+                    .internal_variant_package(&variable.type_, *index as u16)
+                    .is_some()
+                {
+                    return Term::Infinite { variable };
+                }
+
                 let fields = fields
                     .iter()
                     .enumerate()
@@ -29,7 +29,7 @@ pub fn pretty(writer: &mut impl Utf8Writer, src: &EcoString, path: &Utf8Path) ->
         .map_err(|error| Error::Parse {
             path: path.to_path_buf(),
             src: src.clone(),
-            error: Box::new(error),
+            errors: error.errors,
         })?;
     let intermediate = Intermediate::from_extra(&parsed.extra, src);
     Formatter::with_comments(&intermediate)
@@ -571,6 +571,15 @@ impl<'comments> Formatter<'comments> {
                 .append(" ")
                 .append(self.const_expr(right)),
 
+            Constant::BinOp {
+                name, left, right, ..
+            } => self
+                .const_expr(left)
+                .append(break_("", " ").append(name.to_doc()))
+                .nest(INDENT)
+                .append(" ")
+                .append(self.const_expr(right)),
+
             Constant::RecordUpdate {
                 module,
                 name,
@@ -847,6 +856,7 @@ impl<'comments> Formatter<'comments> {
             body,
             publicity,
             deprecation,
+            must_use,
             return_annotation,
             return_type: _,
             documentation: _,
@@ -859,6 +869,7 @@ impl<'comments> Formatter<'comments> {
         let attributes = AttributesPrinter::new()
             .set_deprecation(deprecation)
             .set_internal(*publicity)
+            .set_must_use(*must_use)
             .set_external_erlang(external_erlang)
             .set_external_javascript(external_javascript)
             .to_doc();
@@ -1011,7 +1022,9 @@ impl<'comments> Formatter<'comments> {
         let _ = self.pop_empty_lines(pattern.location().end);
 
         let (keyword, message) = match kind {
-            AssignmentKind::Let | AssignmentKind::Generated => ("let ", None),
+            AssignmentKind::Let | AssignmentKind::Generated | AssignmentKind::LetElse { .. } => {
+                ("let ", None)
+            }
             AssignmentKind::Assert { message, .. } => ("let assert ", message.as_ref()),
         };
 
@@ -1027,6 +1040,13 @@ impl<'comments> Formatter<'comments> {
             .append(" =")
             .append(self.assigned_value(value));
 
+        let doc = match kind {
+            AssignmentKind::LetElse { body, .. } => {
+                docvec![doc, " else ", self.braced_branch(body)]
+            }
+            AssignmentKind::Let | AssignmentKind::Generated | AssignmentKind::Assert { .. } => doc,
+        };
+
         commented(
             self.append_as_message(doc, PrecedingAs::Expression, message),
             comments,
@@ -1060,6 +1080,8 @@ impl<'comments> Formatter<'comments> {
 
             UntypedExpr::String { value, .. } => self.string(value),
 
+            UntypedExpr::InterpolatedString { segments, .. } => self.interpolated_string(segments),
+
             UntypedExpr::Block {
                 statements,
                 location,
@@ -1118,6 +1140,17 @@ impl<'comments> Formatter<'comments> {
                 location,
             } => self.case(subjects, clauses.as_deref().unwrap_or_default(), location),
 
+            UntypedExpr::SubjectlessCase { clauses, location } => {
+                self.subjectless_case(clauses, location)
+            }
+
+            UntypedExpr::If {
+                condition,
+                then,
+                otherwise,
+                ..
+            } => self.if_(condition, then, otherwise.as_deref()),
+
             UntypedExpr::FieldAccess {
                 label, container, ..
             } => if let UntypedExpr::TupleIndex { .. } = container.as_ref() {
@@ -1167,6 +1200,61 @@ impl<'comments> Formatter<'comments> {
         }
     }
 
+    fn interpolated_string<'a>(
+        &mut self,
+        segments: &'a [InterpolatedStringSegment],
+    ) -> Document<'a> {
+        let mut doc = "\"".to_doc();
+        let mut multiline = false;
+        for segment in segments {
+            doc = match segment {
+                InterpolatedStringSegment::Literal { value, .. } => {
+                    multiline |= value.contains('\n');
+                    doc.append(value.to_doc())
+                }
+                InterpolatedStringSegment::Expression {
+                    location,
+                    expression,
+                } => {
+                    let interpolation = self.interpolation(location, expression);
+                    docvec![doc, "\\{", interpolation, "}"]
+                }
+            };
+        }
+
+        let doc = doc.append("\"");
+        if multiline { doc.force_break() } else { doc }
+    }
+
+    /// The expression of an interpolation, without its delimiters. It's kept
+    /// right next to them unless it has comments, in which case it is put on
+    /// its own lines.
+    fn interpolation<'a>(
+        &mut self,
+        location: &SrcSpan,
+        expression: &'a UntypedExpr,
+    ) -> Document<'a> {
+        let leading_comments = self.pop_comments(expression.location().start);
+        let leading_comments = printed_comments(leading_comments, true);
+        let expression = self.expr(expression).group();
+        let trailing_comments = self.pop_comments(location.end);
+        let trailing_comments = printed_comments(trailing_comments, false);
+
+        if leading_comments.is_none() && trailing_comments.is_none() {
+            return expression;
+        }
+
+        let mut body = line();
+        if let Some(leading_comments) = leading_comments {
+            body = body.append(leading_comments);
+        }
+        body = body.append(expression);
+        if let Some(trailing_comments) = trailing_comments {
+            body = docvec![body, line(), trailing_comments];
+        }
+        docvec![body.nest(INDENT), line()].force_break()
+    }
+
     fn bin_op_string<'a>(&self, string: &'a EcoString) -> Document<'a> {
         let lines = string.split('\n').collect_vec();
         match lines.as_slice() {
@@ -1322,12 +1410,15 @@ impl<'comments> Formatter<'comments> {
             | UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
             | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Block { .. }
             | UntypedExpr::Var { .. }
             | UntypedExpr::Fn { .. }
             | UntypedExpr::List { .. }
             | UntypedExpr::Call { .. }
             | UntypedExpr::Case { .. }
+            | UntypedExpr::SubjectlessCase { .. }
+            | UntypedExpr::If { .. }
             | UntypedExpr::FieldAccess { .. }
             | UntypedExpr::Tuple { .. }
             | UntypedExpr::TupleIndex { .. }
@@ -1452,23 +1543,97 @@ impl<'comments> Formatter<'comments> {
                 .map(|(i, c)| self.clause(c, i as u32).group()),
         );
 
+        subjects_doc
+            .append(line().append(clauses_doc).nest(INDENT))
+            .append(self.case_closing_bracket(location))
+            .force_break()
+    }
+
+    fn subjectless_case<'a>(
+        &mut self,
+        clauses: &'a [SubjectlessClause],
+        location: &'a SrcSpan,
+    ) -> Document<'a> {
+        let clauses_doc = concat(
+            clauses
+                .iter()
+                .enumerate()
+                .map(|(i, c)| self.subjectless_clause(c, i as u32).group()),
+        );
+
+        "case {"
+            .to_doc()
+            .append(line().append(clauses_doc).nest(INDENT))
+            .append(self.case_closing_bracket(location))
+            .force_break()
+    }
+
+    fn case_closing_bracket<'a>(&mut self, location: &SrcSpan) -> Document<'a> {
         // We get all remaining comments that come before the case's closing
         // bracket. If there's any we add those before the closing bracket
         // instead of moving those out of the case expression.
         // Otherwise those would be moved out of the case expression.
         let comments = self.pop_comments(location.end);
-        let closing_bracket = match printed_comments(comments, false) {
+        match printed_comments(comments, false) {
             None => docvec![line(), "}"],
             Some(comment) => docvec![line(), comment]
                 .nest(INDENT)
                 .append(line())
                 .append("}"),
-        };
+        }
+    }
 
-        subjects_doc
-            .append(line().append(clauses_doc).nest(INDENT))
-            .append(closing_bracket)
-            .force_break()
+    fn if_<'a>(
+        &mut self,
+        condition: &'a UntypedExpr,
+        then: &'a UntypedExpr,
+        otherwise: Option<&'a UntypedExpr>,
+    ) -> Document<'a> {
+        let doc = docvec![
+            "if ",
+            self.expr(condition).group(),
+            " ",
+            self.braced_branch(then)
+        ];
+        match otherwise {
+            None => doc,
+            Some(otherwise) => docvec![doc, " else ", self.braced_branch(otherwise)],
+        }
+    }
+
+    fn braced_branch<'a>(&mut self, branch: &'a UntypedExpr) -> Document<'a> {
+        match branch {
+            UntypedExpr::Block {
+                statements,
+                location,
+            } => self.block(location, statements, true),
+
+            // An `else if` is the only other kind of branch produced by the
+            // parser, the body of a `let else` is always a block.
+            UntypedExpr::If { .. }
+            | UntypedExpr::Int { .. }
+            | UntypedExpr::Float { .. }
+            | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
+            | UntypedExpr::Var { .. }
+            | UntypedExpr::Fn { .. }
+            | UntypedExpr::List { .. }
+            | UntypedExpr::Call { .. }
+            | UntypedExpr::BinOp { .. }
+            | UntypedExpr::PipeLine { .. }
+            | UntypedExpr::Case { .. }
+            | UntypedExpr::SubjectlessCase { .. }
+            | UntypedExpr::FieldAccess { .. }
+            | UntypedExpr::Tuple { .. }
+            | UntypedExpr::TupleIndex { .. }
+            | UntypedExpr::Todo { .. }
+            | UntypedExpr::Panic { .. }
+            | UntypedExpr::Echo { .. }
+            | UntypedExpr::BitArray { .. }
+            | UntypedExpr::RecordUpdate { .. }
+            | UntypedExpr::NegateBool { .. }
+            | UntypedExpr::NegateInt { .. } => self.expr(branch),
+        }
     }
 
     pub fn record_update<'a>(
@@ -1587,6 +1752,7 @@ impl<'comments> Formatter<'comments> {
             } => self.bin_op(name, left, right, nest_steps),
             UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Block { .. }
             | UntypedExpr::Var { .. }
             | UntypedExpr::Fn { .. }
@@ -1594,6 +1760,8 @@ impl<'comments> Formatter<'comments> {
             | UntypedExpr::Call { .. }
             | UntypedExpr::PipeLine { .. }
             | UntypedExpr::Case { .. }
+            | UntypedExpr::SubjectlessCase { .. }
+            | UntypedExpr::If { .. }
             | UntypedExpr::FieldAccess { .. }
             | UntypedExpr::Tuple { .. }
             | UntypedExpr::TupleIndex { .. }
@@ -1797,6 +1965,7 @@ impl<'comments> Formatter<'comments> {
         let doc_comments = self.doc_comments(constructor.location.start);
         let attributes = AttributesPrinter::new()
             .set_deprecation(&constructor.deprecation)
+            .set_internal(constructor.publicity)
             .to_doc();
 
         let doc = if constructor.arguments.is_empty() {
@@ -2012,7 +2181,11 @@ impl<'comments> Formatter<'comments> {
                 }
             }
 
-            UntypedExpr::Case { .. } => line().append(self.expr(expr)).nest(INDENT),
+            UntypedExpr::Case { .. } | UntypedExpr::SubjectlessCase { .. } => {
+                line().append(self.expr(expr)).nest(INDENT)
+            }
+
+            UntypedExpr::If { .. } => " ".to_doc().append(self.expr(expr)),
 
             UntypedExpr::Block {
                 statements,
@@ -2023,6 +2196,7 @@ impl<'comments> Formatter<'comments> {
             UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
             | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Var { .. }
             | UntypedExpr::Call { .. }
             | UntypedExpr::BinOp { .. }
@@ -2044,10 +2218,13 @@ impl<'comments> Formatter<'comments> {
 
     fn assigned_value<'a>(&mut self, expr: &'a UntypedExpr) -> Document<'a> {
         match expr {
-            UntypedExpr::Case { .. } => " ".to_doc().append(self.expr(expr)).group(),
+            UntypedExpr::Case { .. }
+            | UntypedExpr::SubjectlessCase { .. }
+            | UntypedExpr::If { .. } => " ".to_doc().append(self.expr(expr)).group(),
             UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
             | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Block { .. }
             | UntypedExpr::Var { .. }
             | UntypedExpr::Fn { .. }
@@ -2125,6 +2302,37 @@ impl<'comments> Formatter<'comments> {
         }
     }
 
+    fn subjectless_clause<'a>(
+        &mut self,
+        clause: &'a SubjectlessClause,
+        index: u32,
+    ) -> Document<'a> {
+        let space_before = self.pop_empty_lines(clause.location.start);
+        let comments = self.pop_comments(clause.location.start);
+
+        let condition_doc = match &clause.condition {
+            Some(condition) => self.expr(condition).group(),
+            None => "_".to_doc(),
+        };
+        let clause_doc = condition_doc
+            .append(" ->")
+            .append(self.case_clause_value(&clause.then).group())
+            .group();
+
+        let clause_doc = match printed_comments(comments, false) {
+            Some(comments) => comments.append(line()).append(clause_doc),
+            None => clause_doc,
+        };
+
+        if index == 0 {
+            clause_doc
+        } else if space_before {
+            lines(2).append(clause_doc)
+        } else {
+            line().append(clause_doc)
+        }
+    }
+
     fn alternative_patterns<'a>(&mut self, clause: &'a UntypedClause) -> Document<'a> {
         let has_guard = clause.guard.is_some();
         let has_multiple_subjects = clause.pattern.len() > 1;
@@ -2425,6 +2633,7 @@ impl<'comments> Formatter<'comments> {
             UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
             | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Block { .. }
             | UntypedExpr::Var { .. }
             | UntypedExpr::Fn { .. }
@@ -2433,6 +2642,8 @@ impl<'comments> Formatter<'comments> {
             | UntypedExpr::BinOp { .. }
             | UntypedExpr::PipeLine { .. }
             | UntypedExpr::Case { .. }
+            | UntypedExpr::SubjectlessCase { .. }
+            | UntypedExpr::If { .. }
             | UntypedExpr::FieldAccess { .. }
             | UntypedExpr::Tuple { .. }
             | UntypedExpr::TupleIndex { .. }
@@ -2686,6 +2897,7 @@ impl<'comments> Formatter<'comments> {
             UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
             | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Block { .. }
             | UntypedExpr::Var { .. }
             | UntypedExpr::Fn { .. }
@@ -2693,6 +2905,8 @@ impl<'comments> Formatter<'comments> {
             | UntypedExpr::Call { .. }
             | UntypedExpr::PipeLine { .. }
             | UntypedExpr::Case { .. }
+            | UntypedExpr::SubjectlessCase { .. }
+            | UntypedExpr::If { .. }
             | UntypedExpr::FieldAccess { .. }
             | UntypedExpr::Tuple { .. }
             | UntypedExpr::TupleIndex { .. }
@@ -2714,6 +2928,7 @@ impl<'comments> Formatter<'comments> {
             UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
             | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Block { .. }
             | UntypedExpr::Var { .. }
             | UntypedExpr::Fn { .. }
@@ -2721,6 +2936,8 @@ impl<'comments> Formatter<'comments> {
             | UntypedExpr::Call { .. }
             | UntypedExpr::PipeLine { .. }
             | UntypedExpr::Case { .. }
+            | UntypedExpr::SubjectlessCase { .. }
+            | UntypedExpr::If { .. }
             | UntypedExpr::FieldAccess { .. }
             | UntypedExpr::Tuple { .. }
             | UntypedExpr::TupleIndex { .. }
@@ -2846,12 +3063,15 @@ impl<'comments> Formatter<'comments> {
             UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
             | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Var { .. }
             | UntypedExpr::Fn { .. }
             | UntypedExpr::List { .. }
             | UntypedExpr::Call { .. }
             | UntypedExpr::PipeLine { .. }
             | UntypedExpr::Case { .. }
+            | UntypedExpr::SubjectlessCase { .. }
+            | UntypedExpr::If { .. }
             | UntypedExpr::FieldAccess { .. }
             | UntypedExpr::Tuple { .. }
             | UntypedExpr::TupleIndex { .. }
@@ -3512,6 +3732,8 @@ fn is_breakable_argument(expr: &UntypedExpr, arity: usize) -> bool {
         UntypedExpr::Fn { .. }
         | UntypedExpr::Block { .. }
         | UntypedExpr::Case { .. }
+        | UntypedExpr::SubjectlessCase { .. }
+        | UntypedExpr::If { .. }
         | UntypedExpr::List { .. }
         | UntypedExpr::Tuple { .. }
         | UntypedExpr::BitArray { .. } => true,
@@ -3519,6 +3741,7 @@ fn is_breakable_argument(expr: &UntypedExpr, arity: usize) -> bool {
         UntypedExpr::Int { .. }
         | UntypedExpr::Float { .. }
         | UntypedExpr::String { .. }
+        | UntypedExpr::InterpolatedString { .. }
         | UntypedExpr::Var { .. }
         | UntypedExpr::BinOp { .. }
         | UntypedExpr::PipeLine { .. }
@@ -3599,6 +3822,7 @@ struct AttributesPrinter<'a> {
     external_javascript: &'a Option<(EcoString, EcoString, SrcSpan)>,
     deprecation: &'a Deprecation,
     internal: bool,
+    must_use: bool,
 }
 
 impl<'a> AttributesPrinter<'a> {
@@ -3608,6 +3832,7 @@ impl<'a> AttributesPrinter<'a> {
             external_javascript: &None,
             deprecation: &Deprecation::NotDeprecated,
             internal: false,
+            must_use: false,
         }
     }
 
@@ -3636,6 +3861,11 @@ impl<'a> AttributesPrinter<'a> {
         self.deprecation = deprecation;
         self
     }
+
+    pub fn set_must_use(mut self, must_use: bool) -> Self {
+        self.must_use = must_use;
+        self
+    }
 }
 
 impl<'a> Documentable<'a> for AttributesPrinter<'a> {
@@ -3643,8 +3873,22 @@ impl<'a> Documentable<'a> for AttributesPrinter<'a> {
         let mut attributes = vec![];
 
         // @deprecated attribute
-        if let Deprecation::Deprecated { message } = self.deprecation {
-            attributes.push(docvec!["@deprecated(\"", message, "\")"])
+        match self.deprecation {
+            Deprecation::NotDeprecated => (),
+            Deprecation::Deprecated {
+                message,
+                since: None,
+            } => attributes.push(docvec!["@deprecated(\"", message, "\")"]),
+            Deprecation::Deprecated {
+                message,
+                since: Some(since),
+            } => attributes.push(docvec![
+                "@deprecated(\"",
+                message,
+                "\", since: \"",
+                since,
+                "\")"
+            ]),
         };
 
         // @external attributes
@@ -3661,6 +3905,11 @@ impl<'a> Documentable<'a> for AttributesPrinter<'a> {
             attributes.push("@internal".to_doc());
         };
 
+        // @must_use attribute
+        if self.must_use {
+            attributes.push("@must_use".to_doc());
+        };
+
         if attributes.is_empty() {
             nil()
         } else {
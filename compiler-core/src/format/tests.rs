@@ -13,10 +13,13 @@ mod external_fn;
 mod external_types;
 mod function;
 mod guards;
+mod if_;
 mod imports;
+mod let_else;
 mod lists;
 mod pipeline;
 mod record_update;
+mod subjectless_case;
 mod tuple;
 mod use_;
 
@@ -5201,6 +5204,17 @@ pub type Tiger =
     );
 }
 
+#[test]
+fn deprecated_function_with_since() {
+    assert_format!(
+        r#"@deprecated("Use wobble instead", since: "1.4.0")
+pub fn wibble() {
+  Nil
+}
+"#
+    );
+}
+
 // https://github.com/gleam-lang/gleam/issues/2423
 #[test]
 fn prefix_as() {
@@ -5377,6 +5391,102 @@ fn multiline_string_are_not_broken_with_string_concatenation_if_they_fit() {
     );
 }
 
+#[test]
+fn interpolated_string() {
+    assert_format!(
+        r#"pub fn main() {
+  "user \{name} not found"
+}
+"#
+    );
+}
+
+#[test]
+fn interpolated_string_expression_is_formatted() {
+    assert_format_rewrite!(
+        r#"pub fn main() {
+  "total: \{ int.to_string(  a+b ) }"
+}
+"#,
+        r#"pub fn main() {
+  "total: \{int.to_string(a + b)}"
+}
+"#
+    );
+}
+
+#[test]
+fn interpolated_string_expression_is_not_broken_by_the_text_around_it() {
+    assert_format!(
+        r#"pub fn main() {
+  "\{wibble(wobble, wubble)} and a very long piece of text that goes past the line limit"
+}
+"#
+    );
+}
+
+#[test]
+fn long_interpolated_string_expression_is_broken() {
+    assert_format_rewrite!(
+        r#"pub fn main() {
+  "\{wibble(a_very_long_argument_name, another_very_long_argument_name, and_one_more)}"
+}
+"#,
+        r#"pub fn main() {
+  "\{wibble(
+    a_very_long_argument_name,
+    another_very_long_argument_name,
+    and_one_more,
+  )}"
+}
+"#
+    );
+}
+
+#[test]
+fn interpolated_string_with_comment() {
+    assert_format!(
+        r#"pub fn main() {
+  "Hello, \{
+    // The name is never empty
+    name
+  }!"
+}
+"#
+    );
+}
+
+#[test]
+fn interpolated_string_with_trailing_comment() {
+    assert_format!(
+        r#"pub fn main() {
+  "Hello, \{
+    name
+    // The name is never empty
+  }!"
+}
+"#
+    );
+}
+
+#[test]
+fn interpolated_string_with_comment_on_the_same_line() {
+    assert_format_rewrite!(
+        r#"pub fn main() {
+  "Hello, \{name // The name is never empty
+  }!"
+}
+"#,
+        r#"pub fn main() {
+  "Hello, \{
+    name
+    // The name is never empty
+  }!"
+}
+"#
+    );
+}
+
 #[test]
 fn nesting_goes_back_to_normal_after_multiline_string() {
     assert_format!(
@@ -5778,6 +5888,29 @@ pub const wibble = 1
     );
 }
 
+#[test]
+fn must_use_attribute_on_function() {
+    assert_format!(
+        r#"@must_use
+pub fn main() {
+  todo
+}
+"#
+    );
+}
+
+#[test]
+fn must_use_and_internal_attributes_on_function() {
+    assert_format!(
+        r#"@internal
+@must_use
+pub fn main() {
+  todo
+}
+"#
+    );
+}
+
 #[test]
 fn comments_inside_contant_list() {
     assert_format!(
@@ -6000,6 +6133,28 @@ fn const_concat_short_unbroken() {
     );
 }
 
+#[test]
+fn const_arithmetic() {
+    assert_format!(
+        r#"const size = 4 * 1024 + 2
+
+const ratio = 1.0 /. 3.0
+"#
+    );
+}
+
+#[test]
+fn const_arithmetic_long() {
+    assert_format_rewrite!(
+        r#"const size = some_very_long_constant_name * another_very_long_constant_name + yet_another_constant
+"#,
+        r#"const size = some_very_long_constant_name
+  * another_very_long_constant_name
+  + yet_another_constant
+"#
+    );
+}
+
 #[test]
 fn const_concat_long_including_list() {
     assert_format_rewrite!(
@@ -306,6 +306,31 @@ fn commented_constructors3() {
     );
 }
 
+#[test]
+fn internal_variant() {
+    assert_format!(
+        r#"pub type Wibble {
+  Wibble
+  @internal
+  Wobble(Int)
+}
+"#
+    );
+}
+
+#[test]
+fn deprecated_internal_variant() {
+    assert_format!(
+        r#"pub type Wibble {
+  Wibble
+  @deprecated("Use Wibble instead")
+  @internal
+  Wobble(Int)
+}
+"#
+    );
+}
+
 #[test]
 fn deprecated_variant_1() {
     assert_format!(
@@ -0,0 +1,91 @@
+use crate::assert_format;
+
+#[test]
+fn if_without_else() {
+    assert_format!(
+        r#"pub fn main() {
+  if x {
+    io.println("wibble")
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn if_else() {
+    assert_format!(
+        r#"pub fn main() {
+  if x {
+    1
+  } else {
+    2
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn if_else_if() {
+    assert_format!(
+        r#"pub fn main() {
+  if x > 1 {
+    1
+  } else if x < -1 {
+    -1
+  } else {
+    0
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn if_as_assigned_value() {
+    assert_format!(
+        r#"pub fn main() {
+  let y = if x {
+    1
+  } else {
+    2
+  }
+  y
+}
+"#
+    );
+}
+
+#[test]
+fn if_in_case_clause() {
+    assert_format!(
+        r#"pub fn main() {
+  case x {
+    Ok(y) -> if y {
+      1
+    } else {
+      2
+    }
+    Error(_) -> 0
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn if_with_comments() {
+    assert_format!(
+        r#"pub fn main() {
+  if x {
+    // wibble
+    1
+  } else {
+    // wobble
+    2
+  }
+}
+"#
+    );
+}
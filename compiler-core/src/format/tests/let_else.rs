@@ -0,0 +1,59 @@
+use crate::{assert_format, assert_format_rewrite};
+
+#[test]
+fn let_else() {
+    assert_format!(
+        r#"pub fn main() {
+  let Ok(x) = wibble() else {
+    panic
+  }
+  x
+}
+"#
+    );
+}
+
+#[test]
+fn let_else_with_multiple_statements() {
+    assert_format!(
+        r#"pub fn main() {
+  let Ok(x) = wibble() else {
+    io.println("wobble")
+    panic
+  }
+  x
+}
+"#
+    );
+}
+
+#[test]
+fn let_else_with_annotation() {
+    assert_format!(
+        r#"pub fn main() {
+  let Ok(x): Result(Int, Nil) = wibble() else {
+    panic
+  }
+  x
+}
+"#
+    );
+}
+
+#[test]
+fn let_else_body_is_broken_like_if_branches() {
+    assert_format_rewrite!(
+        r#"pub fn main() {
+  let Ok(x) = wibble() else { panic as "not ok" }
+  x
+}
+"#,
+        r#"pub fn main() {
+  let Ok(x) = wibble() else {
+    panic as "not ok"
+  }
+  x
+}
+"#
+    );
+}
@@ -0,0 +1,79 @@
+use crate::{assert_format, assert_format_rewrite};
+
+#[test]
+fn subjectless_case() {
+    assert_format!(
+        r#"pub fn main() {
+  case {
+    x > 0 -> "positive"
+    x < 0 -> "negative"
+    _ -> "zero"
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn subjectless_case_on_one_line() {
+    assert_format_rewrite!(
+        r#"pub fn main() {
+  case { x > 0 -> 1 _ -> 2 }
+}
+"#,
+        r#"pub fn main() {
+  case {
+    x > 0 -> 1
+    _ -> 2
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn subjectless_case_with_comments_and_empty_lines() {
+    assert_format!(
+        r#"pub fn main() {
+  case {
+    // Positive numbers
+    x > 0 -> 1
+
+    // Everything else
+    _ -> 2
+    // The end
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn subjectless_case_as_assigned_value() {
+    assert_format!(
+        r#"pub fn main() {
+  let y = case {
+    x -> 1
+    _ -> 2
+  }
+  y
+}
+"#
+    );
+}
+
+#[test]
+fn subjectless_case_with_block_clause() {
+    assert_format!(
+        r#"pub fn main() {
+  case {
+    wibble(x) && wobble(x) -> {
+      io.println("wibble")
+      1
+    }
+    _ -> 2
+  }
+}
+"#
+    );
+}
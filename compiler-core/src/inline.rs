@@ -475,7 +475,9 @@ impl Inliner<'_> {
 
     fn assignment_kind(&mut self, kind: AssignmentKind<TypedExpr>) -> AssignmentKind<TypedExpr> {
         match kind {
-            AssignmentKind::Let | AssignmentKind::Generated => kind,
+            AssignmentKind::Let | AssignmentKind::Generated | AssignmentKind::LetElse { .. } => {
+                kind
+            }
             AssignmentKind::Assert {
                 location,
                 assert_keyword_start,
@@ -1995,6 +1997,7 @@ impl InlinableValueConstructor {
                     external_erlang: None,
                     external_javascript: None,
                     purity: Purity::Unknown,
+                    must_use: false,
                 }
             }
             InlinableValueConstructor::Record { name, module } => ValueConstructorVariant::Record {
@@ -793,6 +793,9 @@ impl<'module, 'a> Generator<'module, 'a> {
                     // We can't just return the right-hand side of a `let assert`
                     // assignment; we still need to check that the pattern matches.
                     AssignmentKind::Assert { .. } => {}
+                    AssignmentKind::LetElse { .. } => {
+                        panic!("let else assignments should not reach code generation")
+                    }
                 },
 
                 Statement::Use(use_) => return self.child_expression(&use_.call),
@@ -2016,6 +2019,10 @@ impl<'module, 'a> Generator<'module, 'a> {
                 panic!("record updates should not reach code generation")
             }
 
+            Constant::BinOp { .. } => {
+                panic!("constant arithmetic should not reach code generation")
+            }
+
             Constant::Invalid { .. } => {
                 panic!("invalid constants should not reach code generation")
             }
@@ -2366,6 +2373,7 @@ impl<'module, 'a> Generator<'module, 'a> {
             | Constant::String { .. }
             | Constant::RecordUpdate { .. }
             | Constant::StringConcatenation { .. }
+            | Constant::BinOp { .. }
             | Constant::Invalid { .. } => self.constant_expression(Context::Guard, expression),
         }
     }
@@ -22,7 +22,9 @@ mod echo;
 mod externals;
 mod functions;
 mod generics;
+mod if_;
 mod inlining;
+mod let_else;
 mod lists;
 mod modules;
 mod numbers;
@@ -32,6 +34,7 @@ mod records;
 mod recursion;
 mod results;
 mod strings;
+mod subjectless_case;
 mod todo;
 mod tuples;
 mod type_alias;
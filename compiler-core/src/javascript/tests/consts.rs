@@ -136,3 +136,19 @@ pub const jaks_favourite_number = 11
 "
     );
 }
+
+#[test]
+fn const_arithmetic_is_folded() {
+    assert_js!(
+        r#"
+const kib = 1024
+
+const size = 4 * kib + 2
+
+const ratio = 1.5 *. 2.0
+
+pub fn main() {
+  #(size, ratio)
+}"#
+    );
+}
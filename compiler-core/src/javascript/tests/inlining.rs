@@ -251,6 +251,19 @@ fn add(a, b) { a + b }
     );
 }
 
+#[test]
+fn inline_function_capture_with_hole_in_middle_in_pipe() {
+    assert_js!(
+        "
+pub fn main() {
+  2 |> triple(1, _, 3)
+}
+
+fn triple(a, b, c) { #(a, b, c) }
+"
+    );
+}
+
 #[test]
 fn inlining_works_through_blocks() {
     assert_js!(
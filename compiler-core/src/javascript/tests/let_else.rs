@@ -0,0 +1,37 @@
+use crate::assert_js;
+
+#[test]
+fn let_else() {
+    assert_js!(
+        r#"
+pub fn main(x) {
+  let Ok(y) = x else { panic }
+  y
+}
+"#
+    );
+}
+
+#[test]
+fn let_else_with_following_statements() {
+    assert_js!(
+        r#"
+pub fn main(x) {
+  let Ok(y) = x else { panic as "not ok" }
+  let z = y + 1
+  z * 2
+}
+"#
+    );
+}
+
+#[test]
+fn let_else_as_last_statement() {
+    assert_js!(
+        r#"
+pub fn main(x) {
+  let Ok(_) = x else { todo }
+}
+"#
+    );
+}
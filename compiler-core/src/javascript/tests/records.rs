@@ -165,3 +165,113 @@ pub fn main() {
 "#
     );
 }
+
+#[test]
+fn const_record_label_shorthand() {
+    assert_js!(
+        "
+pub type Person {
+  Person(name: String, age: Int)
+}
+
+pub const name = \"Alice\"
+pub const age = 30
+pub const shorthand = Person(name:, age:)
+pub const explicit = Person(name: name, age: age)
+",
+    );
+}
+
+#[test]
+fn const_record_update_label_shorthand() {
+    assert_js!(
+        "
+pub type Person {
+  Person(name: String, age: Int)
+}
+
+pub const name = \"Bob\"
+pub const alice = Person(\"Alice\", 30)
+pub const shorthand = Person(..alice, name:)
+pub const explicit = Person(..alice, name: name)
+",
+    );
+}
+
+#[test]
+fn positional_field_access() {
+    assert_js!(
+        "
+pub type Cat {
+  Cat(String, Int)
+}
+
+pub fn main(cat: Cat) {
+  #(cat.0, cat.1)
+}
+",
+    );
+}
+
+#[test]
+fn const_record_update_imported_record() {
+    assert_js!(
+        (
+            "other",
+            r#"
+pub type Wibble {
+  Wibble(a: String, b: String, c: fn() -> String)
+}
+
+const b = "b"
+
+pub fn wobble() -> String {
+  "wobble"
+}
+
+pub const wibble = Wibble("a", b, wobble)
+"#
+        ),
+        r#"
+import other
+
+pub const w = other.Wibble(..other.wibble, a: "w")
+"#,
+    );
+}
+
+#[test]
+fn const_record_update_imported_record_referencing_another_module() {
+    assert_js!(
+        (
+            "third",
+            r#"
+pub fn wobble() -> String {
+  "wobble"
+}
+
+pub fn wubble() -> String {
+  "wubble"
+}
+"#
+        ),
+        (
+            "other",
+            r#"
+import third.{wobble as wub} as th
+
+pub type Wibble {
+  Wibble(a: String, b: fn() -> String, c: fn() -> String)
+}
+
+pub const wibble = Wibble("a", wub, th.wubble)
+"#
+        ),
+        r#"
+import other
+import third as t
+
+pub const w = other.Wibble(..other.wibble, a: "w")
+"#,
+    );
+}
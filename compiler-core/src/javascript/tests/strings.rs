@@ -253,3 +253,14 @@ pub fn main() {
 "#
     );
 }
+
+#[test]
+fn interpolated_string() {
+    assert_js!(
+        r#"
+pub fn main(name) {
+  "user \{name} not found"
+}
+"#,
+    );
+}
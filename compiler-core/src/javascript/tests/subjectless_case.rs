@@ -0,0 +1,29 @@
+use crate::assert_js;
+
+#[test]
+fn subjectless_case() {
+    assert_js!(
+        r#"
+pub fn main(x) {
+  case {
+    x > 0 -> 1
+    x < 0 -> -1
+    _ -> 0
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn subjectless_case_with_only_a_catch_all() {
+    assert_js!(
+        r#"
+pub fn main(x) {
+  case {
+    _ -> x
+  }
+}
+"#
+    );
+}
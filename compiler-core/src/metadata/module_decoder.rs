@@ -173,6 +173,7 @@ impl ModuleDecoder {
         } else {
             Deprecation::Deprecated {
                 message: self.string(deprecation)?,
+                since: self.optional_string(self.str(reader.get_deprecated_since()?)?),
             }
         };
         Ok(TypeConstructor {
@@ -197,6 +198,7 @@ impl ModuleDecoder {
         } else {
             Deprecation::Deprecated {
                 message: self.string(deprecation)?,
+                since: self.optional_string(self.str(reader.get_deprecation_since()?)?),
             }
         };
         let parameters = read_vec!(&reader.get_parameters()?, self, type_);
@@ -311,6 +313,7 @@ impl ModuleDecoder {
                 type_value_constructor_parameter
             ),
             documentation: self.optional_string(self.str(reader.get_documentation()?)?),
+            publicity: self.publicity(reader.get_publicity()?)?,
         })
     }
 
@@ -346,6 +349,7 @@ impl ModuleDecoder {
         } else {
             Deprecation::Deprecated {
                 message: self.string(deprecation)?,
+                since: self.optional_string(self.str(reader.get_deprecated_since()?)?),
             }
         };
         Ok(ValueConstructor {
@@ -629,6 +633,7 @@ impl ModuleDecoder {
             external_erlang: self.optional_external(reader.get_external_erlang()?)?,
             external_javascript: self.optional_external(reader.get_external_javascript()?)?,
             purity,
+            must_use: reader.get_must_use(),
         })
     }
 
@@ -293,7 +293,13 @@ impl<'a> ModuleEncoder<'a> {
         builder.set_module(&constructor.module);
         builder.set_deprecated(match &constructor.deprecation {
             Deprecation::NotDeprecated => "",
-            Deprecation::Deprecated { message } => message,
+            Deprecation::Deprecated { message, .. } => message,
+        });
+        builder.set_deprecated_since(match &constructor.deprecation {
+            Deprecation::NotDeprecated | Deprecation::Deprecated { since: None, .. } => "",
+            Deprecation::Deprecated {
+                since: Some(since), ..
+            } => since,
         });
         self.build_publicity(builder.reborrow().init_publicity(), constructor.publicity);
         let type_builder = builder.reborrow().init_type();
@@ -322,7 +328,13 @@ impl<'a> ModuleEncoder<'a> {
         builder.set_module(&constructor.module);
         builder.set_deprecation(match &constructor.deprecation {
             Deprecation::NotDeprecated => "",
-            Deprecation::Deprecated { message } => message,
+            Deprecation::Deprecated { message, .. } => message,
+        });
+        builder.set_deprecation_since(match &constructor.deprecation {
+            Deprecation::NotDeprecated | Deprecation::Deprecated { since: None, .. } => "",
+            Deprecation::Deprecated {
+                since: Some(since), ..
+            } => since,
         });
         self.build_publicity(builder.reborrow().init_publicity(), constructor.publicity);
         let type_builder = builder.reborrow().init_type();
@@ -344,6 +356,7 @@ impl<'a> ModuleEncoder<'a> {
     ) {
         builder.set_name(&constructor.name);
         builder.set_documentation(constructor.documentation.as_deref().unwrap_or_default());
+        self.build_publicity(builder.reborrow().init_publicity(), constructor.publicity);
         let mut builder = builder.init_parameters(constructor.parameters.len() as u32);
         for (i, parameter) in constructor.parameters.iter().enumerate() {
             self.build_type_value_constructor_parameter(
@@ -370,7 +383,13 @@ impl<'a> ModuleEncoder<'a> {
     ) {
         builder.set_deprecated(match &constructor.deprecation {
             Deprecation::NotDeprecated => "",
-            Deprecation::Deprecated { message } => message,
+            Deprecation::Deprecated { message, .. } => message,
+        });
+        builder.set_deprecated_since(match &constructor.deprecation {
+            Deprecation::NotDeprecated | Deprecation::Deprecated { since: None, .. } => "",
+            Deprecation::Deprecated {
+                since: Some(since), ..
+            } => since,
         });
 
         self.build_publicity(builder.reborrow().init_publicity(), constructor.publicity);
@@ -462,12 +481,14 @@ impl<'a> ModuleEncoder<'a> {
                 external_erlang,
                 external_javascript,
                 purity,
+                must_use,
             } => {
                 let mut builder = builder.init_module_fn();
                 builder.set_name(name);
                 builder.set_module(module);
                 builder.set_arity(*arity as u16);
                 builder.set_documentation(doc.as_ref().map(EcoString::as_str).unwrap_or_default());
+                builder.set_must_use(*must_use);
 
                 let mut purity_builder = builder.reborrow().init_purity();
                 match purity {
@@ -593,6 +614,10 @@ impl<'a> ModuleEncoder<'a> {
                 panic!("record updates should not reach code generation")
             }
 
+            Constant::BinOp { .. } => {
+                panic!("constant arithmetic should not reach code generation")
+            }
+
             Constant::Invalid { .. } => {
                 panic!("invalid constants should not reach code generation")
             }
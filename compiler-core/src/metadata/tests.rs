@@ -487,6 +487,7 @@ fn module_type_to_constructors_mapping() {
                     name: "One".into(),
                     parameters: vec![],
                     documentation: Some("Some documentation".into()),
+                    publicity: Publicity::Public,
                 }],
                 opaque: Opaque::NotOpaque,
             },
@@ -508,6 +509,55 @@ fn module_type_to_constructors_mapping() {
     assert_eq!(roundtrip(&module), module);
 }
 
+#[test]
+fn module_type_to_constructors_mapping_with_internal_variant() {
+    let module = ModuleInterface {
+        warnings: vec![],
+        is_internal: false,
+        package: "some_package".into(),
+        origin: Origin::Src,
+        name: "a".into(),
+        types: HashMap::new(),
+        types_value_constructors: [(
+            "SomeType".into(),
+            TypeVariantConstructors {
+                type_parameters_ids: vec![0, 1, 2],
+                variants: vec![
+                    TypeValueConstructor {
+                        name: "One".into(),
+                        parameters: vec![],
+                        documentation: None,
+                        publicity: Publicity::Public,
+                    },
+                    TypeValueConstructor {
+                        name: "Two".into(),
+                        parameters: vec![],
+                        documentation: None,
+                        publicity: Publicity::Internal {
+                            attribute_location: Some(SrcSpan { start: 4, end: 13 }),
+                        },
+                    },
+                ],
+                opaque: Opaque::NotOpaque,
+            },
+        )]
+        .into(),
+        accessors: HashMap::new(),
+        values: HashMap::new(),
+        line_numbers: LineNumbers::new(""),
+        src_path: "some_path".into(),
+        minimum_required_version: Version::new(0, 1, 0),
+        type_aliases: HashMap::new(),
+        documentation: Vec::new(),
+        contains_echo: false,
+
+        references: References::default(),
+        inline_functions: HashMap::new(),
+    };
+
+    assert_eq!(roundtrip(&module), module);
+}
+
 #[test]
 fn module_fn_value() {
     let module = ModuleInterface {
@@ -545,6 +595,7 @@ fn module_fn_value() {
                         can_run_on_javascript: true,
                     },
                     purity: Purity::Pure,
+                    must_use: false,
                 },
             },
         )]
@@ -579,6 +630,120 @@ fn deprecated_module_fn_value() {
                 publicity: Publicity::Public,
                 deprecation: Deprecation::Deprecated {
                     message: "wibble wobble".into(),
+                    since: None,
+                },
+                type_: type_::int(),
+                variant: ValueConstructorVariant::ModuleFn {
+                    documentation: Some("wobble!".into()),
+                    name: "one".into(),
+                    field_map: None,
+                    module: "a".into(),
+                    arity: 5,
+                    location: SrcSpan {
+                        start: 535,
+                        end: 1100,
+                    },
+                    external_erlang: None,
+                    external_javascript: None,
+                    implementations: Implementations {
+                        gleam: true,
+                        uses_erlang_externals: false,
+                        uses_javascript_externals: false,
+                        can_run_on_erlang: true,
+                        can_run_on_javascript: true,
+                    },
+                    purity: Purity::Pure,
+                    must_use: false,
+                },
+            },
+        )]
+        .into(),
+        line_numbers: LineNumbers::new(""),
+        src_path: "some_path".into(),
+        minimum_required_version: Version::new(0, 1, 0),
+        type_aliases: HashMap::new(),
+        documentation: Vec::new(),
+        contains_echo: false,
+
+        references: References::default(),
+        inline_functions: HashMap::new(),
+    };
+    assert_eq!(roundtrip(&module), module);
+}
+
+#[test]
+fn must_use_module_fn_value() {
+    let module = ModuleInterface {
+        warnings: vec![],
+        is_internal: false,
+        package: "some_package".into(),
+        origin: Origin::Src,
+        name: "a".into(),
+        types: HashMap::new(),
+        types_value_constructors: HashMap::new(),
+        accessors: HashMap::new(),
+        values: [(
+            "one".into(),
+            ValueConstructor {
+                publicity: Publicity::Public,
+                deprecation: Deprecation::NotDeprecated,
+                type_: type_::int(),
+                variant: ValueConstructorVariant::ModuleFn {
+                    documentation: Some("wobble!".into()),
+                    name: "one".into(),
+                    field_map: None,
+                    module: "a".into(),
+                    arity: 5,
+                    location: SrcSpan {
+                        start: 535,
+                        end: 1100,
+                    },
+                    external_erlang: None,
+                    external_javascript: None,
+                    implementations: Implementations {
+                        gleam: true,
+                        uses_erlang_externals: false,
+                        uses_javascript_externals: false,
+                        can_run_on_erlang: true,
+                        can_run_on_javascript: true,
+                    },
+                    purity: Purity::Pure,
+                    must_use: true,
+                },
+            },
+        )]
+        .into(),
+        line_numbers: LineNumbers::new(""),
+        src_path: "some_path".into(),
+        minimum_required_version: Version::new(0, 1, 0),
+        type_aliases: HashMap::new(),
+        documentation: Vec::new(),
+        contains_echo: false,
+
+        references: References::default(),
+        inline_functions: HashMap::new(),
+    };
+    assert_eq!(roundtrip(&module), module);
+}
+
+#[test]
+fn deprecated_module_fn_value_with_since() {
+    let module = ModuleInterface {
+        warnings: vec![],
+        is_internal: false,
+        package: "some_package".into(),
+        origin: Origin::Src,
+        name: "a".into(),
+        types: HashMap::new(),
+        types_value_constructors: HashMap::new(),
+        accessors: HashMap::new(),
+        values: [(
+            "one".into(),
+            ValueConstructor {
+                publicity: Publicity::Public,
+                deprecation: Deprecation::Deprecated {
+                    message: "wibble wobble".into(),
+                    since: Some("1.4.0".into()),
                 },
                 type_: type_::int(),
                 variant: ValueConstructorVariant::ModuleFn {
@@ -601,6 +766,7 @@ fn deprecated_module_fn_value() {
                         can_run_on_javascript: true,
                     },
                     purity: Purity::Pure,
+                    must_use: false,
                 },
             },
         )]
@@ -655,6 +821,7 @@ fn private_module_fn_value() {
                         can_run_on_javascript: true,
                     },
                     purity: Purity::Pure,
+                    must_use: false,
                 },
             },
         )]
@@ -711,6 +878,7 @@ fn module_fn_value_regression() {
                         can_run_on_javascript: true,
                     },
                     purity: Purity::TrustedPure,
+                    must_use: false,
                 },
             },
         )]
@@ -766,6 +934,7 @@ fn module_fn_value_with_field_map() {
                         can_run_on_javascript: true,
                     },
                     purity: Purity::Pure,
+                    must_use: false,
                 },
             },
         )]
@@ -1465,6 +1634,47 @@ fn deprecated_type() {
                 parameters: vec![],
                 deprecation: Deprecation::Deprecated {
                     message: "oh no".into(),
+                    since: None,
+                },
+                documentation: None,
+            },
+        )]
+        .into(),
+        types_value_constructors: HashMap::new(),
+        values: HashMap::new(),
+        accessors: HashMap::new(),
+        line_numbers: LineNumbers::new(""),
+        src_path: "some_path".into(),
+        minimum_required_version: Version::new(0, 1, 0),
+        type_aliases: HashMap::new(),
+        documentation: Vec::new(),
+        contains_echo: false,
+
+        references: References::default(),
+        inline_functions: HashMap::new(),
+    };
+    assert_eq!(roundtrip(&module), module);
+}
+
+#[test]
+fn deprecated_type_with_since() {
+    let module = ModuleInterface {
+        warnings: vec![],
+        is_internal: false,
+        package: "some_package".into(),
+        origin: Origin::Src,
+        name: "a/b".into(),
+        types: [(
+            "ListIntType".into(),
+            TypeConstructor {
+                type_: type_::list(type_::int()),
+                publicity: Publicity::Public,
+                origin: Default::default(),
+                module: "the/module".into(),
+                parameters: vec![],
+                deprecation: Deprecation::Deprecated {
+                    message: "oh no".into(),
+                    since: Some("0.2.0".into()),
                 },
                 documentation: None,
             },
@@ -1523,6 +1733,7 @@ fn module_fn_value_with_external_implementations() {
                         can_run_on_javascript: true,
                     },
                     purity: Purity::Impure,
+                    must_use: false,
                 },
             },
         )]
@@ -1604,6 +1815,7 @@ fn internal_module_fn() {
                         can_run_on_javascript: true,
                     },
                     purity: Purity::Unknown,
+                    must_use: false,
                 },
             },
         )]
@@ -1664,6 +1876,7 @@ fn internal_annotated_module_fn() {
                         can_run_on_javascript: true,
                     },
                     purity: Purity::Impure,
+                    must_use: false,
                 },
             },
         )]
@@ -1716,6 +1929,7 @@ fn type_variable_ids_in_constructors_are_shared() {
                         },
                     ],
                     documentation: None,
+                    publicity: Publicity::Public,
                 }],
                 opaque: Opaque::NotOpaque,
             },
@@ -1757,6 +1971,7 @@ fn type_variable_ids_in_constructors_are_shared() {
                     },
                 ],
                 documentation: None,
+                publicity: Publicity::Public,
             }],
             opaque: Opaque::NotOpaque,
         },
@@ -1850,6 +2065,47 @@ fn module_with_type_aliases() {
     assert_eq!(roundtrip(&module), module);
 }
 
+#[test]
+fn deprecated_type_alias_with_since() {
+    let module = ModuleInterface {
+        warnings: vec![],
+        is_internal: false,
+        package: "some_package".into(),
+        origin: Origin::Src,
+        name: "some_module".into(),
+        types: HashMap::new(),
+        types_value_constructors: HashMap::new(),
+        values: HashMap::new(),
+        accessors: HashMap::new(),
+        line_numbers: LineNumbers::new(""),
+        src_path: "some_path".into(),
+        minimum_required_version: Version::new(0, 1, 0),
+        type_aliases: [(
+            "MyAlias".into(),
+            TypeAliasConstructor {
+                publicity: Publicity::Public,
+                module: "some_module".into(),
+                type_: prelude::int(),
+                arity: 1,
+                deprecation: Deprecation::Deprecated {
+                    message: "Use Int instead".into(),
+                    since: Some("2.0.0".into()),
+                },
+                documentation: Some("Some documentation".into()),
+                origin: Default::default(),
+                parameters: vec![type_::generic_var(0)],
+            },
+        )]
+        .into(),
+        documentation: Vec::new(),
+        contains_echo: false,
+
+        references: References::default(),
+        inline_functions: HashMap::new(),
+    };
+    assert_eq!(roundtrip(&module), module);
+}
+
 #[test]
 fn module_with_documentation() {
     let module = ModuleInterface {
@@ -1896,6 +2152,7 @@ fn module_with_opaque_type() {
                     name: "One".into(),
                     parameters: vec![],
                     documentation: Some("Some documentation".into()),
+                    publicity: Publicity::Public,
                 }],
                 opaque: Opaque::Opaque,
             },
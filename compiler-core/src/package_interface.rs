@@ -279,14 +279,17 @@ impl ImplementationsInterface {
 pub struct DeprecationInterface {
     /// The reason for the deprecation.
     message: EcoString,
+    /// The version the item was deprecated in, if any.
+    since: Option<EcoString>,
 }
 
 impl DeprecationInterface {
     fn from_deprecation(deprecation: &Deprecation) -> Option<DeprecationInterface> {
         match deprecation {
             Deprecation::NotDeprecated => None,
-            Deprecation::Deprecated { message } => Some(DeprecationInterface {
+            Deprecation::Deprecated { message, since } => Some(DeprecationInterface {
                 message: message.clone(),
+                since: since.clone(),
             }),
         }
     }
@@ -427,6 +430,7 @@ impl ModuleInterface {
                             ..
                         }) => variants
                             .iter()
+                            .filter(|constructor| !constructor.publicity.is_internal())
                             .map(|constructor| TypeConstructorInterface {
                                 documentation: constructor.documentation.clone(),
                                 name: constructor.name.clone(),
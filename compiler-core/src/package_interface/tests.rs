@@ -262,6 +262,16 @@ pub fn main() { Wob }
     );
 }
 
+#[test]
+pub fn deprecated_function_with_since() {
+    assert_package_interface!(
+        r#"
+@deprecated("deprecation message", since: "1.4.0")
+pub fn main() { Nil }
+"#
+    );
+}
+
 #[test]
 pub fn imported_type() {
     assert_package_interface!(
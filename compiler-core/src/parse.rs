@@ -60,12 +60,13 @@ use crate::ast::{
     Arg, ArgNames, Assert, AssignName, Assignment, AssignmentKind, BinOp, BitArrayOption,
     BitArraySegment, BitArraySize, CAPTURE_VARIABLE, CallArg, Clause, ClauseGuard, Constant,
     CustomType, Definition, Function, FunctionLiteralKind, HasLocation, Import, IntOperator,
-    Module, ModuleConstant, Pattern, Publicity, RecordBeingUpdated, RecordConstructor,
-    RecordConstructorArg, RecordUpdateArg, SrcSpan, Statement, TailPattern, TargetedDefinition,
-    TodoKind, TypeAlias, TypeAst, TypeAstConstructor, TypeAstFn, TypeAstHole, TypeAstTuple,
-    TypeAstVar, UnqualifiedImport, UntypedArg, UntypedClause, UntypedClauseGuard, UntypedConstant,
-    UntypedDefinition, UntypedExpr, UntypedModule, UntypedPattern, UntypedRecordUpdateArg,
-    UntypedStatement, UntypedUseAssignment, Use, UseAssignment,
+    InterpolatedStringSegment, Module, ModuleConstant, Pattern, Publicity, RecordBeingUpdated,
+    RecordConstructor, RecordConstructorArg, RecordUpdateArg, SrcSpan, Statement,
+    SubjectlessClause, TailPattern, TargetedDefinition, TodoKind, TypeAlias, TypeAst,
+    TypeAstConstructor, TypeAstFn, TypeAstHole, TypeAstTuple, TypeAstVar, UnqualifiedImport,
+    UntypedArg, UntypedClause, UntypedClauseGuard, UntypedConstant, UntypedDefinition, UntypedExpr,
+    UntypedModule, UntypedPattern, UntypedRecordUpdateArg, UntypedStatement, UntypedUseAssignment,
+    Use, UseAssignment,
 };
 use crate::build::Target;
 use crate::error::wrap;
@@ -77,7 +78,8 @@ use crate::type_::expression::{Implementations, Purity};
 use crate::warning::{DeprecatedSyntaxWarning, WarningEmitter};
 use camino::Utf8PathBuf;
 use ecow::EcoString;
-use error::{LexicalError, ParseError, ParseErrorType};
+use error::{LexicalError, ParseError, ParseErrorType, Replacement};
+use hexpm::version::Version;
 use lexer::{LexResult, Spanned};
 use num_bigint::BigInt;
 use serde::{Deserialize, Serialize};
@@ -85,7 +87,7 @@ use std::cmp::Ordering;
 use std::collections::VecDeque;
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
-pub use token::Token;
+pub use token::{StringPart, Token};
 use vec1::{Vec1, vec1};
 
 #[cfg(test)]
@@ -97,6 +99,18 @@ pub struct Parsed {
     pub extra: ModuleExtra,
 }
 
+/// A module that could not be parsed without errors.
+///
+/// The parser can recover from some errors, such as a block that is never
+/// closed, so `parsed` holds all the definitions it could still make sense of.
+/// This way tooling like the language server can keep working while a file is
+/// being edited.
+#[derive(Debug)]
+pub struct ModuleParseError {
+    pub parsed: Box<Parsed>,
+    pub errors: Vec1<ParseError>,
+}
+
 /// We use this to keep track of the `@internal` annotation for top level
 /// definitions. Instead of using just a boolean we want to keep track of the
 /// source position of the annotation in case it is present. This way we can
@@ -126,11 +140,12 @@ struct Attributes {
     external_erlang: Option<(EcoString, EcoString, SrcSpan)>,
     external_javascript: Option<(EcoString, EcoString, SrcSpan)>,
     internal: InternalAttribute,
+    must_use: bool,
 }
 
 impl Attributes {
     fn has_function_only(&self) -> bool {
-        self.external_erlang.is_some() || self.external_javascript.is_some()
+        self.external_erlang.is_some() || self.external_javascript.is_some() || self.must_use
     }
 
     fn has_external_for(&self, target: Target) -> bool {
@@ -158,10 +173,16 @@ pub fn parse_module(
     path: Utf8PathBuf,
     src: &str,
     warnings: &WarningEmitter,
-) -> Result<Parsed, ParseError> {
+) -> Result<Parsed, ModuleParseError> {
     let lex = lexer::make_tokenizer(src);
     let mut parser = Parser::new(lex);
-    let mut parsed = parser.parse_module()?;
+    let mut parsed = match parser.parse_module() {
+        Ok(parsed) => parsed,
+        Err(mut error) => {
+            error.parsed.extra = parser.extra;
+            return Err(error);
+        }
+    };
     parsed.extra = parser.extra;
 
     let src = EcoString::from(src);
@@ -192,7 +213,8 @@ pub fn parse_statement_sequence(src: &str) -> Result<Vec1<UntypedStatement>, Par
     let lex = lexer::make_tokenizer(src);
     let mut parser = Parser::new(lex);
     let expr = parser.parse_statement_seq();
-    let expr = parser.ensure_no_errors_or_remaining_input(expr)?;
+    let expr = parser.ensure_no_errors_or_remaining_input(expr);
+    let expr = parser.ensure_no_recovered_errors(expr)?;
     match expr {
         Some((e, _)) => Ok(e),
         _ => parse_error(ParseErrorType::ExpectedExpr, SrcSpan { start: 0, end: 0 }),
@@ -207,7 +229,8 @@ pub fn parse_const_value(src: &str) -> Result<Constant<(), ()>, ParseError> {
     let lex = lexer::make_tokenizer(src);
     let mut parser = Parser::new(lex);
     let expr = parser.parse_const_value();
-    let expr = parser.ensure_no_errors_or_remaining_input(expr)?;
+    let expr = parser.ensure_no_errors_or_remaining_input(expr);
+    let expr = parser.ensure_no_recovered_errors(expr)?;
     match expr {
         Some(e) => Ok(e),
         _ => parse_error(ParseErrorType::ExpectedExpr, SrcSpan { start: 0, end: 0 }),
@@ -221,12 +244,17 @@ pub fn parse_const_value(src: &str) -> Result<Constant<(), ()>, ParseError> {
 pub struct Parser<T: Iterator<Item = LexResult>> {
     tokens: T,
     lex_errors: Vec<LexicalError>,
+    /// Errors the parser has recovered from, they are reported once the whole
+    /// module has been parsed.
+    errors: Vec<ParseError>,
     warnings: Vec<DeprecatedSyntaxWarning>,
     tok0: Option<Spanned>,
     tok1: Option<Spanned>,
     extra: ModuleExtra,
     doc_comments: VecDeque<(u32, EcoString)>,
     detached_doc_comments: Vec<SrcSpan>,
+    /// The byte index of the first character of each line after the first.
+    line_starts: Vec<u32>,
 }
 impl<T> Parser<T>
 where
@@ -236,21 +264,36 @@ where
         let mut parser = Parser {
             tokens: input,
             lex_errors: vec![],
+            errors: vec![],
             warnings: vec![],
             tok0: None,
             tok1: None,
             extra: ModuleExtra::new(),
             doc_comments: VecDeque::new(),
             detached_doc_comments: Vec::new(),
+            line_starts: Vec::new(),
         };
         parser.advance();
         parser.advance();
         parser
     }
 
-    fn parse_module(&mut self) -> Result<Parsed, ParseError> {
-        let definitions = Parser::series_of(self, &Parser::parse_definition, None);
-        let definitions = self.ensure_no_errors_or_remaining_input(definitions)?;
+    fn parse_module(&mut self) -> Result<Parsed, ModuleParseError> {
+        // Definitions are collected one at a time rather than with
+        // `series_of` so the ones parsed before an error are not lost.
+        let mut definitions = vec![];
+        let result = loop {
+            match self.parse_definition() {
+                Ok(Some(definition)) => {
+                    definitions.push(definition);
+                    self.skip_semicolons();
+                }
+                Ok(None) => break Ok(()),
+                Err(error) => break Err(error),
+            }
+        };
+        let result = self.ensure_no_errors_or_remaining_input(result);
+
         let module = Module {
             name: "".into(),
             documentation: vec![],
@@ -259,10 +302,41 @@ where
             names: Default::default(),
             unused_definition_positions: Default::default(),
         };
-        Ok(Parsed {
+        let parsed = Parsed {
             module,
             extra: Default::default(),
-        })
+        };
+
+        let errors = match result {
+            // Any other error is most likely caused by the invalid code the
+            // lexer ran into, so only the lex errors are reported.
+            Err(ParseError {
+                error: ParseErrorType::LexError { .. },
+                ..
+            }) => self
+                .lex_errors
+                .iter()
+                .map(|&error| ParseError {
+                    error: ParseErrorType::LexError { error },
+                    location: error.location,
+                })
+                .collect(),
+            Err(error) => {
+                let mut errors = std::mem::take(&mut self.errors);
+                errors.push(error);
+                errors
+            }
+            Ok(()) => std::mem::take(&mut self.errors),
+        };
+        let errors: Vec<_> = errors.into_iter().map(unexpected_semicolon).collect();
+
+        match Vec1::try_from_vec(errors) {
+            Ok(errors) => Err(ModuleParseError {
+                parsed: Box::new(parsed),
+                errors,
+            }),
+            Err(_) => Ok(parsed),
+        }
     }
 
     // The way the parser is currently implemented, it cannot exit immediately while advancing
@@ -312,6 +386,32 @@ where
         }
     }
 
+    // The statement and constant test interfaces report a single error, unlike
+    // `parse_module`, so without this the errors the parser recovered from
+    // would be lost and the input would be reported as successfully parsed.
+    // Recovered errors are found before any error that stopped parsing, so
+    // they are reported first. Lex errors still take precedence as they can
+    // cause all the other errors.
+    #[cfg(test)]
+    fn ensure_no_recovered_errors<A>(
+        &mut self,
+        parse_result: Result<A, ParseError>,
+    ) -> Result<A, ParseError> {
+        match (parse_result, self.errors.first()) {
+            (
+                Err(
+                    error @ ParseError {
+                        error: ParseErrorType::LexError { .. },
+                        ..
+                    },
+                ),
+                _,
+            ) => Err(error),
+            (_, Some(error)) => Err(error.clone()),
+            (parse_result, None) => parse_result,
+        }
+    }
+
     fn parse_definition(&mut self) -> Result<Option<TargetedDefinition>, ParseError> {
         let mut attributes = Attributes::default();
         let location = self.parse_attributes(&mut attributes)?;
@@ -375,7 +475,10 @@ where
         }?;
 
         match (def, location) {
-            (Some(definition), _) if definition.is_function() || definition.is_custom_type() => {
+            (Some(definition), _)
+                if definition.is_function()
+                    || (definition.is_custom_type() && !attributes.must_use) =>
+            {
                 Ok(Some(TargetedDefinition {
                     definition,
                     target: attributes.target,
@@ -418,6 +521,16 @@ where
     fn parse_expression_inner(
         &mut self,
         is_let_binding: bool,
+    ) -> Result<Option<UntypedExpr>, ParseError> {
+        self.parse_expression_starting_with(None, is_let_binding)
+    }
+
+    /// Parses an expression whose first unit may have already been parsed,
+    /// for when the parser had to look inside it to decide what it was.
+    fn parse_expression_starting_with(
+        &mut self,
+        mut first_unit: Option<UntypedExpr>,
+        is_let_binding: bool,
     ) -> Result<Option<UntypedExpr>, ParseError> {
         // uses the simple operator parser algorithm
         let mut opstack = vec![];
@@ -432,7 +545,11 @@ where
         let mut expression_unit_context = ExpressionUnitContext::Other;
 
         loop {
-            match self.parse_expression_unit(expression_unit_context)? {
+            let unit = match first_unit.take() {
+                Some(unit) => Some(self.parse_expression_unit_suffix(unit)?),
+                None => self.parse_expression_unit(expression_unit_context)?,
+            };
+            match unit {
                 Some(unit) => {
                     self.post_process_expression_unit(&unit, is_let_binding)?;
                     estack.push(unit)
@@ -500,6 +617,57 @@ where
         Ok(())
     }
 
+    // examples:
+    //   "\{name} not found"
+    //   "\{a} + \{b} = \{a + b}"
+    //
+    // The lexer has already split the string into its parts, so each
+    // interpolation is parsed from its own tokens.
+    fn parse_interpolated_string(
+        &mut self,
+        parts: Vec<StringPart>,
+        location: SrcSpan,
+    ) -> Result<UntypedExpr, ParseError> {
+        let mut segments = Vec::with_capacity(parts.len());
+        for part in parts {
+            let segment = match part {
+                StringPart::Literal { location, value } => {
+                    InterpolatedStringSegment::Literal { location, value }
+                }
+                StringPart::Interpolation { location, tokens } => {
+                    let mut parser = Parser::new(tokens.into_iter().map(Ok));
+                    let expression = parser.parse_expression();
+                    self.errors.append(&mut parser.errors);
+                    self.warnings.append(&mut parser.warnings);
+                    let expression = match expression? {
+                        Some(expression) => expression,
+                        None => return parse_error(ParseErrorType::ExpectedExpr, location),
+                    };
+                    if let Some((start, token, end)) = parser.next_tok() {
+                        return parse_error(
+                            ParseErrorType::UnexpectedToken {
+                                token,
+                                expected: vec!["`}`".into()],
+                                hint: None,
+                            },
+                            SrcSpan { start, end },
+                        );
+                    }
+                    // Comments and empty lines inside the interpolation are
+                    // needed by the formatter like those of the rest of the
+                    // module.
+                    self.extra.merge(parser.extra);
+                    InterpolatedStringSegment::Expression {
+                        location,
+                        expression,
+                    }
+                }
+            };
+            segments.push(segment);
+        }
+        Ok(UntypedExpr::InterpolatedString { location, segments })
+    }
+
     // examples:
     //   1
     //   "one"
@@ -512,7 +680,7 @@ where
         &mut self,
         context: ExpressionUnitContext,
     ) -> Result<Option<UntypedExpr>, ParseError> {
-        let mut expr = match self.tok0.take() {
+        let expr = match self.tok0.take() {
             Some((start, Token::String { value }, end)) => {
                 self.advance();
                 UntypedExpr::String {
@@ -520,6 +688,10 @@ where
                     value,
                 }
             }
+            Some((start, Token::InterpolatedString { parts }, end)) => {
+                self.advance();
+                self.parse_interpolated_string(parts, SrcSpan { start, end })?
+            }
             Some((start, Token::Int { value, int_value }, end)) => {
                 self.advance();
                 UntypedExpr::Int {
@@ -784,46 +956,29 @@ where
                 self.parse_block(start)?
             }
 
+            // case with no subjects, or with a block as its first subject
+            Some((start, Token::Case, case_e))
+                if matches!(self.tok1, Some((_, Token::LeftBrace, _))) =>
+            {
+                self.advance();
+                let (left_brace_start, left_brace_end) = self.expect_one(&Token::LeftBrace)?;
+                self.parse_case_opening_with_brace(
+                    start,
+                    case_e,
+                    SrcSpan::new(left_brace_start, left_brace_end),
+                )?
+            }
+
             // case
             Some((start, Token::Case, case_e)) => {
                 self.advance();
-                let subjects =
-                    Parser::series_of(self, &Parser::parse_expression, Some(&Token::Comma))?;
-                if self.maybe_one(&Token::LeftBrace).is_some() {
-                    let clauses = Parser::series_of(self, &Parser::parse_case_clause, None)?;
-                    let (_, end) =
-                        self.expect_one_following_series(&Token::RightBrace, "a case clause")?;
-                    if subjects.is_empty() {
-                        return parse_error(
-                            ParseErrorType::ExpectedExpr,
-                            SrcSpan { start, end: case_e },
-                        );
-                    } else {
-                        UntypedExpr::Case {
-                            location: SrcSpan { start, end },
-                            subjects,
-                            clauses: Some(clauses),
-                        }
-                    }
-                } else {
-                    UntypedExpr::Case {
-                        location: SrcSpan::new(
-                            start,
-                            subjects
-                                .last()
-                                .map(|subject| subject.location().end)
-                                .unwrap_or(case_e),
-                        ),
-                        subjects,
-                        clauses: None,
-                    }
-                }
+                self.parse_case(start, case_e, None)?
             }
 
-            // Helpful error if trying to write an if expression instead of a
-            // case.
-            Some((start, Token::If, end)) => {
-                return parse_error(ParseErrorType::IfExpression, SrcSpan { start, end });
+            // if/else
+            Some((start, Token::If, _)) => {
+                self.advance();
+                self.parse_if(start)?
             }
 
             // Helpful error on possibly trying to group with "(".
@@ -877,6 +1032,18 @@ where
             }
         };
 
+        Ok(Some(self.parse_expression_unit_suffix(expr)?))
+    }
+
+    // examples:
+    //   unit.label
+    //   unit.0
+    //   unit(a, b)
+    //   unit(..record, label: a)
+    fn parse_expression_unit_suffix(
+        &mut self,
+        mut expr: UntypedExpr,
+    ) -> Result<UntypedExpr, ParseError> {
         // field access and call can stack up
         loop {
             match self.maybe_one(&Token::Dot) {
@@ -951,7 +1118,7 @@ where
                                 label: "".into(),
                                 container: Box::new(expr),
                             };
-                            return Ok(Some(expr));
+                            return Ok(expr);
                         }
                     }
                 }
@@ -1002,7 +1169,7 @@ where
             }
         }
 
-        Ok(Some(expr))
+        Ok(expr)
     }
 
     fn add_comment_style_hint(&self, mut err: ParseError) -> ParseError {
@@ -1128,8 +1295,20 @@ where
 
         let mut end = value.location().end;
 
+        if let AssignmentKind::Let = kind
+            && let Some((else_start, else_end)) = self.maybe_one(&Token::Else)
+        {
+            let (body_start, _) = self.expect_one(&Token::LeftBrace)?;
+            let body = self.parse_block(body_start)?;
+            end = body.location().end;
+            kind = AssignmentKind::LetElse {
+                else_location: SrcSpan::new(else_start, else_end),
+                body: Box::new(body),
+            };
+        }
+
         match &mut kind {
-            AssignmentKind::Let | AssignmentKind::Generated => {}
+            AssignmentKind::Let | AssignmentKind::Generated | AssignmentKind::LetElse { .. } => {}
             AssignmentKind::Assert { message, .. } => {
                 if self.maybe_one(&Token::As).is_some() {
                     let message_expression =
@@ -1178,17 +1357,27 @@ where
     //   assignment expr..
     //   assignment assignment..
     fn parse_statement_seq(&mut self) -> Result<Option<(Vec1<UntypedStatement>, u32)>, ParseError> {
-        let mut statements = vec![];
-        let mut start = None;
-        let mut end = 0;
+        self.parse_statement_seq_starting_with(None)
+    }
 
-        // Try and parse as many expressions as possible
-        while let Some(statement) = self.parse_statement()? {
-            if start.is_none() {
-                start = Some(statement.location().start);
-            }
+    fn parse_statement_seq_starting_with(
+        &mut self,
+        first_statement: Option<UntypedStatement>,
+    ) -> Result<Option<(Vec1<UntypedStatement>, u32)>, ParseError> {
+        let mut end = first_statement
+            .as_ref()
+            .map(|statement| statement.location().end)
+            .unwrap_or(0);
+        let mut statements: Vec<_> = first_statement.into_iter().collect();
+
+        // Try and parse as many expressions as possible, stopping if a new
+        // definition starts as the block must have been left unclosed.
+        while !self.at_definition_boundary()
+            && let Some(statement) = self.parse_statement()?
+        {
             end = statement.location().end;
             statements.push(statement);
+            self.skip_semicolons();
         }
 
         match Vec1::try_from_vec(statements) {
@@ -1240,8 +1429,20 @@ where
     }
 
     fn parse_block(&mut self, start: u32) -> Result<UntypedExpr, ParseError> {
-        let body = self.parse_statement_seq()?;
-        let (_, end) = self.expect_one(&Token::RightBrace)?;
+        self.parse_block_starting_with(start, None)
+    }
+
+    fn parse_block_starting_with(
+        &mut self,
+        start: u32,
+        first_statement: Option<UntypedStatement>,
+    ) -> Result<UntypedExpr, ParseError> {
+        let body = self.parse_statement_seq_starting_with(first_statement)?;
+        let body_end = body.as_ref().map(|(_, end)| *end).unwrap_or(start + 1);
+        let end = match self.recover_unclosed_block(SrcSpan::new(start, start + 1), body_end) {
+            Some(end) => end,
+            None => self.expect_one(&Token::RightBrace)?.1,
+        };
         let location = SrcSpan { start, end };
         let statements = match body {
             Some((statements, _)) => statements,
@@ -1258,6 +1459,45 @@ where
         })
     }
 
+    // examples:
+    //   if a { b }
+    //   if a { b } else { c }
+    //   if a { b } else if c { d } else { e }
+    fn parse_if(&mut self, start: u32) -> Result<UntypedExpr, ParseError> {
+        let condition = self.expect_expression()?;
+        let (then_start, _) = self.expect_one(&Token::LeftBrace)?;
+        let then = self.parse_block(then_start)?;
+
+        let otherwise = match self.maybe_one(&Token::Else) {
+            None => None,
+            Some(_) => match self.tok0.take() {
+                Some((if_start, Token::If, _)) => {
+                    self.advance();
+                    Some(self.parse_if(if_start)?)
+                }
+                Some((block_start, Token::LeftBrace, _)) => {
+                    self.advance();
+                    Some(self.parse_block(block_start)?)
+                }
+                t0 => {
+                    self.tok0 = t0;
+                    return self.next_tok_unexpected(vec![
+                        Token::LeftBrace.to_string().into(),
+                        Token::If.to_string().into(),
+                    ]);
+                }
+            },
+        };
+
+        let end = otherwise.as_ref().unwrap_or(&then).location().end;
+        Ok(UntypedExpr::If {
+            location: SrcSpan { start, end },
+            condition: Box::new(condition),
+            then: Box::new(then),
+            otherwise: otherwise.map(Box::new),
+        })
+    }
+
     // The left side of an "=" or a "->"
     fn parse_pattern(
         &mut self,
@@ -1600,6 +1840,60 @@ where
         err
     }
 
+    // Consumes a `=` immediately followed by a `>`, returning the location of
+    // the two characters.
+    fn maybe_fat_arrow(&mut self) -> Option<SrcSpan> {
+        match (&self.tok0, &self.tok1) {
+            (
+                Some((start, Token::Equal, equal_end)),
+                Some((greater_start, Token::Greater, end)),
+            ) if equal_end == greater_start => {
+                let location = SrcSpan::new(*start, *end);
+                self.advance();
+                self.advance();
+                Some(location)
+            }
+            _ => None,
+        }
+    }
+
+    // Returns the location between the end of a case clause's patterns and the
+    // start of the next token, if that token can start an expression and is on
+    // the same line as the patterns.
+    fn missing_case_clause_arrow(&self, head_end: u32) -> Option<SrcSpan> {
+        let start = match &self.tok0 {
+            Some((
+                start,
+                Token::String { .. }
+                | Token::InterpolatedString { .. }
+                | Token::Int { .. }
+                | Token::Float { .. }
+                | Token::Name { .. }
+                | Token::UpName { .. }
+                | Token::Todo
+                | Token::Panic
+                | Token::Echo
+                | Token::Hash
+                | Token::LeftSquare
+                | Token::LtLt
+                | Token::Fn
+                | Token::LeftBrace
+                | Token::Case
+                | Token::Bang
+                | Token::Minus,
+                _,
+            )) => *start,
+            _ => return None,
+        };
+        let next_line = self
+            .line_starts
+            .get(self.line_starts.partition_point(|line| *line <= head_end));
+        match next_line {
+            Some(line) if *line <= start => None,
+            _ => Some(SrcSpan::new(head_end, start)),
+        }
+    }
+
     // examples:
     //   pattern -> expr
     //   pattern, pattern if -> expr
@@ -1623,9 +1917,40 @@ where
                     alternative_patterns.push(patterns);
                 }
                 let guard = self.parse_case_clause_guard()?;
-                let (arr_s, arr_e) = self
-                    .expect_one(&Token::RArrow)
-                    .map_err(|e| self.add_multi_line_clause_hint(e))?;
+                let head_end = match &guard {
+                    Some(guard) => guard.location().end,
+                    None => alternative_patterns
+                        .last()
+                        .unwrap_or(&patterns)
+                        .last()
+                        .map_or(lead.location().end, |pattern| pattern.location().end),
+                };
+                let (arr_s, arr_e) = match self.maybe_fat_arrow() {
+                    Some(location) => {
+                        // `=>` is what other languages use here, so we record
+                        // the error and carry on as if it was `->`.
+                        self.errors.push(ParseError {
+                            error: ParseErrorType::FatArrowInCaseClause,
+                            location,
+                        });
+                        (location.start, location.end)
+                    }
+                    None => match self.missing_case_clause_arrow(head_end) {
+                        // If the clause body follows the patterns on the same
+                        // line the arrow was most likely forgotten, so we
+                        // record the error and carry on as if it was there.
+                        Some(location) => {
+                            self.errors.push(ParseError {
+                                error: ParseErrorType::MissingCaseClauseArrow,
+                                location,
+                            });
+                            (location.start, location.end)
+                        }
+                        None => self
+                            .expect_one(&Token::RArrow)
+                            .map_err(|e| self.add_multi_line_clause_hint(e))?,
+                    },
+                };
                 let then = self.parse_expression()?;
                 match then {
                     Some(then) => Ok(Some(Clause {
@@ -1655,6 +1980,191 @@ where
             _ => Ok(None),
         }
     }
+
+    // examples:
+    //   case a { b -> c }
+    //   case a, b { c, d -> e }
+    //   case { a } { b -> c }
+    fn parse_case(
+        &mut self,
+        start: u32,
+        case_e: u32,
+        first_subject: Option<UntypedExpr>,
+    ) -> Result<UntypedExpr, ParseError> {
+        let subjects = match first_subject {
+            None => Parser::series_of(self, &Parser::parse_expression, Some(&Token::Comma))?,
+            Some(first_subject) => {
+                let mut subjects = vec![];
+                subjects.extend(self.parse_expression_starting_with(Some(first_subject), false)?);
+                if self.maybe_one(&Token::Comma).is_some() {
+                    subjects.extend(Parser::series_of(
+                        self,
+                        &Parser::parse_expression,
+                        Some(&Token::Comma),
+                    )?);
+                }
+                subjects
+            }
+        };
+
+        let Some((left_brace_start, left_brace_end)) = self.maybe_one(&Token::LeftBrace) else {
+            return Ok(UntypedExpr::Case {
+                location: SrcSpan::new(
+                    start,
+                    subjects
+                        .last()
+                        .map(|subject| subject.location().end)
+                        .unwrap_or(case_e),
+                ),
+                subjects,
+                clauses: None,
+            });
+        };
+
+        let clauses = Parser::series_of(self, &Parser::parse_case_clause, None)?;
+        let clauses_end = clauses
+            .last()
+            .map(|clause| clause.location.end)
+            .unwrap_or(left_brace_end);
+        let opened = SrcSpan::new(left_brace_start, left_brace_end);
+        let end = match self.recover_unclosed_block(opened, clauses_end) {
+            Some(end) => end,
+            None => {
+                self.expect_one_following_series(&Token::RightBrace, "a case clause")?
+                    .1
+            }
+        };
+        if subjects.is_empty() {
+            return parse_error(ParseErrorType::ExpectedExpr, SrcSpan { start, end: case_e });
+        }
+        Ok(UntypedExpr::Case {
+            location: SrcSpan { start, end },
+            subjects,
+            clauses: Some(clauses),
+        })
+    }
+
+    // A `case` followed by an opening brace is either a case with no subjects
+    // or a case whose first subject is a block. In a case with no subjects the
+    // first expression after the brace is followed by an arrow, which is never
+    // the case for the first statement of a block.
+    //
+    // examples:
+    //   case { a -> b  _ -> c }
+    //   case { a } { b -> c }
+    fn parse_case_opening_with_brace(
+        &mut self,
+        start: u32,
+        case_e: u32,
+        opened: SrcSpan,
+    ) -> Result<UntypedExpr, ParseError> {
+        // A discard can't start a block, so this can only be a clause.
+        if let Some((_, Token::DiscardName { .. }, _)) = self.tok0 {
+            return self.parse_subjectless_case(start, opened, None);
+        }
+
+        match self.parse_statement()? {
+            Some(Statement::Expression(condition))
+                if matches!(self.tok0, Some((_, Token::RArrow, _))) =>
+            {
+                self.parse_subjectless_case(start, opened, Some(condition))
+            }
+            first_statement => {
+                self.skip_semicolons();
+                let block = self.parse_block_starting_with(opened.start, first_statement)?;
+                self.parse_case(start, case_e, Some(block))
+            }
+        }
+    }
+
+    // examples:
+    //   case { a -> b  _ -> c }
+    //   case { a -> b  c -> d  _ -> e }
+    fn parse_subjectless_case(
+        &mut self,
+        start: u32,
+        opened: SrcSpan,
+        first_condition: Option<UntypedExpr>,
+    ) -> Result<UntypedExpr, ParseError> {
+        let mut clauses = vec![];
+        if let Some(condition) = first_condition {
+            let start = condition.location().start;
+            clauses.push(self.parse_subjectless_case_clause_then(start, Some(condition))?);
+        }
+        clauses.extend(Parser::series_of(
+            self,
+            &Parser::parse_subjectless_case_clause,
+            None,
+        )?);
+        let clauses_end = clauses
+            .last()
+            .map(|clause| clause.location.end)
+            .unwrap_or(opened.end);
+        let end = match self.recover_unclosed_block(opened, clauses_end) {
+            Some(end) => end,
+            None => {
+                self.expect_one_following_series(&Token::RightBrace, "a case clause")?
+                    .1
+            }
+        };
+        Ok(UntypedExpr::SubjectlessCase {
+            location: SrcSpan { start, end },
+            clauses,
+        })
+    }
+
+    fn parse_subjectless_case_clause(&mut self) -> Result<Option<SubjectlessClause>, ParseError> {
+        let (start, condition) = match (&self.tok0, &self.tok1) {
+            (Some((start, Token::DiscardName { name }, _)), Some((_, Token::RArrow, _)))
+                if name == "_" =>
+            {
+                let start = *start;
+                self.advance();
+                (start, None)
+            }
+            _ => match self.parse_expression()? {
+                Some(condition) => (condition.location().start, Some(condition)),
+                None => {
+                    return match self.tok0 {
+                        Some((start, Token::DiscardName { .. }, end)) => {
+                            parse_error(ParseErrorType::IncorrectName, SrcSpan { start, end })
+                        }
+                        _ => Ok(None),
+                    };
+                }
+            },
+        };
+        self.parse_subjectless_case_clause_then(start, condition)
+            .map(Some)
+    }
+
+    fn parse_subjectless_case_clause_then(
+        &mut self,
+        start: u32,
+        condition: Option<UntypedExpr>,
+    ) -> Result<SubjectlessClause, ParseError> {
+        let (arr_s, arr_e) = self
+            .expect_one(&Token::RArrow)
+            .map_err(|e| self.add_multi_line_clause_hint(e))?;
+        match self.parse_expression()? {
+            Some(then) => Ok(SubjectlessClause {
+                location: SrcSpan {
+                    start,
+                    end: then.location().end,
+                },
+                condition,
+                then,
+            }),
+            None => parse_error(
+                ParseErrorType::ExpectedExpr,
+                SrcSpan {
+                    start: arr_s,
+                    end: arr_e,
+                },
+            ),
+        }
+    }
+
     fn parse_patterns(
         &mut self,
         position: PatternPosition,
@@ -1879,7 +2389,13 @@ where
 
             t0 => {
                 self.tok0 = t0;
-                match self.parse_const_value()? {
+                // Guards have their own arithmetic, so the constant is parsed
+                // without any.
+                let constant = match self.parse_const_value_unit()? {
+                    Some(constant) => self.parse_const_maybe_concatenation(constant)?,
+                    None => None,
+                };
+                match constant {
                     Some(const_val) => {
                         // Constant
                         Ok(Some(ClauseGuard::Constant(const_val)))
@@ -2168,9 +2684,17 @@ where
         let return_annotation = self.parse_type_annotation(&Token::RArrow)?;
 
         let (body_start, body, end, end_position) = match self.maybe_one(&Token::LeftBrace) {
-            Some((left_brace_start, _)) => {
+            Some((left_brace_start, left_brace_end)) => {
                 let some_body = self.parse_statement_seq()?;
-                let (_, right_brace_end) = self.expect_one(&Token::RightBrace)?;
+                let body_end = some_body
+                    .as_ref()
+                    .map(|(_, end)| *end)
+                    .unwrap_or(left_brace_end);
+                let opened = SrcSpan::new(left_brace_start, left_brace_end);
+                let right_brace_end = match self.recover_unclosed_block(opened, body_end) {
+                    Some(end) => end,
+                    None => self.expect_one(&Token::RightBrace)?.1,
+                };
                 let end = return_annotation
                     .as_ref()
                     .map(|l| l.location().end)
@@ -2207,6 +2731,7 @@ where
             return_type: (),
             return_annotation,
             deprecation: std::mem::take(&mut attributes.deprecated),
+            must_use: attributes.must_use,
             external_erlang: attributes.external_erlang.take(),
             external_javascript: attributes.external_javascript.take(),
             implementations: Implementations {
@@ -2496,16 +3021,18 @@ where
             let constructors = Parser::series_of(
                 self,
                 &|p| {
-                    // The only attribute supported on constructors is @deprecated
+                    // The only attributes supported on constructors are
+                    // @deprecated and @internal
                     let mut attributes = Attributes::default();
                     let attr_loc = Parser::parse_attributes(p, &mut attributes)?;
 
                     if let Some(attr_span) = attr_loc {
-                        // Expecting all but the deprecated atterbutes to be default
+                        // Expecting all but the deprecated and internal
+                        // attributes to be default
                         if attributes.external_erlang.is_some()
                             || attributes.external_javascript.is_some()
                             || attributes.target.is_some()
-                            || attributes.internal != InternalAttribute::Missing
+                            || attributes.must_use
                         {
                             return parse_error(
                                 ParseErrorType::UnknownAttributeRecordVariant,
@@ -2530,6 +3057,7 @@ where
                                 arguments,
                                 documentation,
                                 deprecation: attributes.deprecated,
+                                publicity: p.publicity(public, attributes.internal)?,
                             }))
                         }
                         _ => Ok(None),
@@ -2943,10 +3471,30 @@ where
                     return Err(e);
                 };
 
+                // ...and nested ones like `import one.two.three` too.
+                let mut path = vec![module, name.clone()];
+                let mut path_end = e.location.end;
+                while let (
+                    Some((_, Token::Dot, _)),
+                    Some((_, Token::Name { name } | Token::UpName { name }, end)),
+                ) = (&self.tok0, &self.tok1)
+                {
+                    path.push(name.clone());
+                    path_end = *end;
+                    self.advance();
+                    self.advance();
+                }
+
+                let replacement = Replacement {
+                    location: SrcSpan::new(start, path_end),
+                    text: path.join("/").into(),
+                };
+                let item = path.pop().expect("pythonic import path item");
                 return Err(ParseError {
                     error: ParseErrorType::IncorrectImportModuleSeparator {
-                        module,
-                        item: name.clone(),
+                        module: path.join("/").into(),
+                        item,
+                        replacement,
                     },
                     location: SrcSpan::new(dot_start, dot_end),
                 });
@@ -3129,13 +3677,67 @@ where
     //   [1,2,3]
     //   wibble <> "wobble"
     fn parse_const_value(&mut self) -> Result<Option<UntypedConstant>, ParseError> {
-        let constant_result = self.parse_const_value_unit();
+        let constant_result = self.parse_const_arithmetic();
         match constant_result {
             Ok(Some(constant)) => self.parse_const_maybe_concatenation(constant),
             _ => constant_result,
         }
     }
 
+    // examples:
+    //   1
+    //   4 * 1024
+    //   size - 1
+    //   1.0 +. 2.0 *. 3.0
+    fn parse_const_arithmetic(&mut self) -> Result<Option<UntypedConstant>, ParseError> {
+        let mut opstack = vec![];
+        let mut estack = vec![];
+        let mut last_op_start = 0;
+        let mut last_op_end = 0;
+        loop {
+            match self.parse_const_value_unit()? {
+                Some(unit) => estack.push(unit),
+                None if estack.is_empty() => return Ok(None),
+                None => {
+                    return parse_error(
+                        ParseErrorType::OpNakedRight,
+                        SrcSpan {
+                            start: last_op_start,
+                            end: last_op_end,
+                        },
+                    );
+                }
+            }
+
+            let Some((op_s, t, op_e)) = self.tok0.take() else {
+                break;
+            };
+
+            let Some(precedence) = const_arithmetic_precedence(&t) else {
+                // Is not an arithmetic operator
+                self.tok0 = Some((op_s, t, op_e));
+                break;
+            };
+
+            self.advance();
+            last_op_start = op_s;
+            last_op_end = op_e;
+            let _ = handle_op(
+                Some(((op_s, t, op_e), precedence)),
+                &mut opstack,
+                &mut estack,
+                &do_reduce_const_arithmetic,
+            );
+        }
+
+        Ok(handle_op(
+            None,
+            &mut opstack,
+            &mut estack,
+            &do_reduce_const_arithmetic,
+        ))
+    }
+
     fn parse_const_value_unit(&mut self) -> Result<Option<UntypedConstant>, ParseError> {
         match self.tok0.take() {
             Some((start, Token::String { value }, end)) => {
@@ -3764,6 +4366,7 @@ where
             | Token::Int { .. }
             | Token::Float { .. }
             | Token::String { .. }
+            | Token::InterpolatedString { .. }
             | Token::CommentDoc { .. }
             | Token::LeftParen
             | Token::RightParen
@@ -3802,6 +4405,7 @@ where
             | Token::LArrow
             | Token::DotDot
             | Token::At
+            | Token::Semicolon
             | Token::EndOfFile
             | Token::CommentNormal
             | Token::CommentModule
@@ -4022,6 +4626,7 @@ functions are declared separately from types.";
                 Token::Int { .. }
                 | Token::Float { .. }
                 | Token::String { .. }
+                | Token::InterpolatedString { .. }
                 | Token::CommentDoc { .. }
                 | Token::LeftParen
                 | Token::RightParen
@@ -4065,6 +4670,7 @@ functions are declared separately from types.";
                 | Token::LArrow
                 | Token::DotDot
                 | Token::At
+                | Token::Semicolon
                 | Token::EndOfFile
                 | Token::CommentNormal
                 | Token::CommentModule
@@ -4108,6 +4714,7 @@ functions are declared separately from types.";
                 Token::Int { .. }
                 | Token::Float { .. }
                 | Token::String { .. }
+                | Token::InterpolatedString { .. }
                 | Token::CommentDoc { .. }
                 | Token::LeftParen
                 | Token::RightParen
@@ -4151,6 +4758,7 @@ functions are declared separately from types.";
                 | Token::LArrow
                 | Token::DotDot
                 | Token::At
+                | Token::Semicolon
                 | Token::EndOfFile
                 | Token::CommentNormal
                 | Token::CommentModule
@@ -4348,6 +4956,62 @@ functions are declared separately from types.";
         }
     }
 
+    // Gleam has no statement terminator, but people coming from other
+    // languages often write one anyway. A run of semicolons is recorded as a
+    // single error and skipped so that parsing can carry on.
+    fn skip_semicolons(&mut self) {
+        let mut semicolons = vec![];
+        while let Some((start, end)) = self.maybe_one(&Token::Semicolon) {
+            semicolons.push(SrcSpan::new(start, end));
+        }
+        let (Some(first), Some(last)) = (semicolons.first(), semicolons.last()) else {
+            return;
+        };
+        let location = SrcSpan::new(first.start, last.end);
+        self.errors.push(ParseError {
+            error: ParseErrorType::SemicolonNotNeeded { semicolons },
+            location,
+        });
+    }
+
+    // If a `}` is expected but the end of the file or the start of a new
+    // definition is found instead, the block opened at `opened` was most likely
+    // left unclosed while the user is still editing it. Rather than giving up
+    // on the whole module the error is recorded and the block is treated as if
+    // it ended at `end`, so that the definitions that follow can be parsed.
+    fn recover_unclosed_block(&mut self, opened: SrcSpan, end: u32) -> Option<u32> {
+        if !self.at_definition_boundary() {
+            return None;
+        }
+        self.errors.push(ParseError {
+            error: ParseErrorType::UnclosedBlock { opened },
+            location: SrcSpan::new(end, end),
+        });
+        Some(end)
+    }
+
+    // Whether the parser has reached the end of the file or a token that can
+    // only start a new definition. `fn` and `const` can also appear inside a
+    // function, so only tokens at the very start of a line are considered: the
+    // formatter never puts anything other than a definition there. A `fn` must
+    // also be followed by a name, or it is an anonymous function.
+    fn at_definition_boundary(&self) -> bool {
+        let start = match (&self.tok0, &self.tok1) {
+            (None, _) => return true,
+            (Some((start, Token::Fn, _)), Some((_, Token::Name { .. }, _)))
+            | (
+                Some((
+                    start,
+                    Token::Pub | Token::Type | Token::Import | Token::Const | Token::At,
+                    _,
+                )),
+                _,
+            ) => start,
+            (Some(_), _) => return false,
+        };
+        self.line_starts.binary_search(start).is_ok()
+    }
+
     // Moves the token stream forward
     fn advance(&mut self) {
         let _ = self.next_tok();
@@ -4375,8 +5039,9 @@ functions are declared separately from types.";
                     self.extra.module_comments.push(SrcSpan { start, end });
                     previous_newline = None;
                 }
-                Some(Ok((start, Token::NewLine, _))) => {
+                Some(Ok((start, Token::NewLine, end))) => {
                     self.extra.new_lines.push(start);
+                    self.line_starts.push(end);
                     // If the previous token is a newline as well that means we
                     // have run into an empty line.
                     if let Some(start) = previous_newline {
@@ -4387,11 +5052,10 @@ functions are declared separately from types.";
                     previous_newline = Some(start);
                 }
 
-                // die on lex error
+                // The lexer carries on after the errors it can recover from,
+                // and stops producing tokens after the ones it can't.
                 Some(Err(err)) => {
-                    nxt = None;
                     self.lex_errors.push(err);
-                    break;
                 }
 
                 Some(Ok(tok)) => {
@@ -4480,6 +5144,7 @@ functions are declared separately from types.";
                 self.parse_deprecated_attribute(start, end, attributes)
             }
             "internal" => self.parse_internal_attribute(start, end, attributes),
+            "must_use" => self.parse_must_use_attribute(start, end, attributes),
             _ => parse_error(ParseErrorType::UnknownAttribute, SrcSpan { start, end }),
         }?;
 
@@ -4547,11 +5212,36 @@ functions are declared separately from types.";
             error: ParseErrorType::ExpectedDeprecationMessage,
             location: SrcSpan { start, end },
         })?;
+        let since = match self.maybe_one(&Token::Comma) {
+            Some(_) => Some(self.parse_deprecated_since()?),
+            None => None,
+        };
         let (_, end) = self.expect_one(&Token::RightParen)?;
-        attributes.deprecated = Deprecation::Deprecated { message };
+        attributes.deprecated = Deprecation::Deprecated { message, since };
         Ok(end)
     }
 
+    // examples:
+    //   since: "1.4.0"
+    fn parse_deprecated_since(&mut self) -> Result<EcoString, ParseError> {
+        let (label_start, label, label_end) = self.expect_name()?;
+        if label != "since" {
+            return parse_error(
+                ParseErrorType::ExpectedDeprecationSince,
+                SrcSpan::new(label_start, label_end),
+            );
+        }
+        let _ = self.expect_one(&Token::Colon)?;
+        let (start, version, end) = self.expect_string()?;
+        match Version::parse(&version) {
+            Ok(_) => Ok(version),
+            Err(_) => parse_error(
+                ParseErrorType::InvalidDeprecationVersion { version },
+                SrcSpan { start, end },
+            ),
+        }
+    }
+
     fn parse_internal_attribute(
         &mut self,
         start: u32,
@@ -4571,6 +5261,19 @@ functions are declared separately from types.";
             }
         }
     }
+
+    fn parse_must_use_attribute(
+        &mut self,
+        start: u32,
+        end: u32,
+        attributes: &mut Attributes,
+    ) -> Result<u32, ParseError> {
+        if attributes.must_use {
+            return parse_error(ParseErrorType::DuplicateAttribute, SrcSpan::new(start, end));
+        }
+        attributes.must_use = true;
+        Ok(end)
+    }
 }
 
 fn concat_pattern_variable_left_hand_side_error<T>(start: u32, end: u32) -> Result<T, ParseError> {
@@ -4671,6 +5374,7 @@ fn tok_to_binop(t: &Token) -> Option<BinOp> {
         | Token::Int { .. }
         | Token::Float { .. }
         | Token::String { .. }
+        | Token::InterpolatedString { .. }
         | Token::CommentDoc { .. }
         | Token::LeftParen
         | Token::RightParen
@@ -4692,6 +5396,7 @@ fn tok_to_binop(t: &Token) -> Option<BinOp> {
         | Token::LArrow
         | Token::DotDot
         | Token::At
+        | Token::Semicolon
         | Token::EndOfFile
         | Token::CommentNormal
         | Token::CommentModule
@@ -4742,6 +5447,58 @@ fn do_reduce_clause_guard(op: Spanned, estack: &mut Vec<UntypedClauseGuard>) {
     }
 }
 
+// Only arithmetic is allowed in constants, and it's folded into a literal
+// during analysis.
+fn const_arithmetic_precedence(t: &Token) -> Option<u8> {
+    match tok_to_binop(t)? {
+        name @ (BinOp::AddInt
+        | BinOp::AddFloat
+        | BinOp::SubInt
+        | BinOp::SubFloat
+        | BinOp::MultInt
+        | BinOp::MultFloat
+        | BinOp::DivInt
+        | BinOp::DivFloat
+        | BinOp::RemainderInt) => Some(name.precedence()),
+
+        BinOp::And
+        | BinOp::Or
+        | BinOp::Eq
+        | BinOp::NotEq
+        | BinOp::LtInt
+        | BinOp::LtEqInt
+        | BinOp::LtFloat
+        | BinOp::LtEqFloat
+        | BinOp::GtEqInt
+        | BinOp::GtInt
+        | BinOp::GtEqFloat
+        | BinOp::GtFloat
+        | BinOp::Concatenate => None,
+    }
+}
+
+fn do_reduce_const_arithmetic(
+    (token_start, token, token_end): Spanned,
+    estack: &mut Vec<UntypedConstant>,
+) {
+    match (estack.pop(), estack.pop(), tok_to_binop(&token)) {
+        (Some(right), Some(left), Some(name)) => estack.push(Constant::BinOp {
+            location: SrcSpan {
+                start: left.location().start,
+                end: right.location().end,
+            },
+            name,
+            name_location: SrcSpan {
+                start: token_start,
+                end: token_end,
+            },
+            left: Box::new(left),
+            right: Box::new(right),
+        }),
+        _ => panic!("Tried to reduce without 2 constants"),
+    }
+}
+
 fn expr_op_reduction(
     (token_start, token, token_end): Spanned,
     l: UntypedExpr,
@@ -4860,6 +5617,25 @@ fn parse_error<T>(error: ParseErrorType, location: SrcSpan) -> Result<T, ParseEr
     Err(ParseError { error, location })
 }
 
+// Semicolons are only skipped after statements and definitions, anywhere else
+// they are an unexpected token. They are never valid Gleam though, so rather
+// than a generic error we tell the user they can be removed.
+fn unexpected_semicolon(error: ParseError) -> ParseError {
+    let ParseErrorType::UnexpectedToken {
+        token: Token::Semicolon,
+        ..
+    } = error.error
+    else {
+        return error;
+    };
+    ParseError {
+        error: ParseErrorType::SemicolonNotNeeded {
+            semicolons: vec![error.location],
+        },
+        location: error.location,
+    }
+}
+
 //
 // Misc Helpers
 //
@@ -26,10 +26,24 @@ pub enum LexicalErrorType {
     DigitOutOfRadix,                                 // 0x012 , 2 is out of radix
     NumTrailingUnderscore,                           // 1_000_ is not allowed
     RadixIntNoValue,                                 // 0x, 0b, 0o without a value
-    MissingExponent,     // 1.0e, for example, where there is no exponent
-    UnexpectedStringEnd, // Unterminated string literal
+    MissingExponent,            // 1.0e, for example, where there is no exponent
+    ExponentLeadingUnderscore,  // 1.0e_1 is not allowed
+    ExponentTrailingUnderscore, // 1.0e1_ is not allowed
+    ExponentDoubleUnderscore,   // 1.0e1__0 is not allowed
+    UnexpectedStringEnd,        // Unterminated string literal
     UnrecognizedToken { tok: char },
     InvalidTripleEqual,
+    UnterminatedInterpolation, // "\{name" with no closing brace
+}
+
+/// An edit that would fix a syntax error, so that editors can apply it
+/// automatically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replacement {
+    /// The span of source code to replace.
+    pub location: SrcSpan,
+    /// The text to put in its place.
+    pub text: EcoString,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -49,6 +63,7 @@ pub enum ParseErrorType {
     ExpectedValue,              // no value after "="
     ExpectedDefinition,         // after attributes
     ExpectedDeprecationMessage, // after "deprecated"
+    ExpectedDeprecationSince,   // @deprecated("wibble", until: "1.0.0")
     ExpectedFunctionDefinition, // after function-only attributes
     ExpectedTargetName,         // after "@target("
     ExprLparStart,              // it seems "(" was used to start an expression
@@ -100,8 +115,7 @@ pub enum ParseErrorType {
         field: EcoString,
         field_type: Option<Box<TypeAst>>,
     },
-    CallInClauseGuard, // case x { _ if f() -> 1 }
-    IfExpression,
+    CallInClauseGuard,                    // case x { _ if f() -> 1 }
     ConstantRecordConstructorNoArguments, // const x = Record()
     TypeDefinitionNoArguments,            // pub type Wibble() { ... }
     UnknownAttributeRecordVariant, // an attribute was used that is not know for a custom type variant
@@ -109,6 +123,8 @@ pub enum ParseErrorType {
     IncorrectImportModuleSeparator {
         module: EcoString,
         item: EcoString,
+        /// Replaces the dotted module path with the slash separated one.
+        replacement: Replacement,
     },
     /// This can happen when there's an empty block in a case clause guard.
     /// For example: `_ if a == {}`
@@ -127,6 +143,20 @@ pub enum ParseErrorType {
         name: EcoString,
         arguments: Vec<EcoString>,
     },
+    FatArrowInCaseClause,   // case x { 1 => 2 }
+    MissingCaseClauseArrow, // case x { 1 2 }
+    // @deprecated("wibble", since: "one") <- the version isn't valid
+    InvalidDeprecationVersion {
+        version: EcoString,
+    },
+    // let x = 1;  <- the location of each of the semicolons in a row
+    SemicolonNotNeeded {
+        semicolons: Vec<SrcSpan>,
+    },
+    // fn main() { 1  <- the end of the file or a new definition is reached
+    UnclosedBlock {
+        opened: SrcSpan,
+    },
 }
 
 pub(crate) struct ParseErrorDetails {
@@ -202,6 +232,16 @@ impl ParseErrorType {
                 extra_labels: vec![],
             },
 
+            ParseErrorType::ExpectedDeprecationSince => ParseErrorDetails {
+                text: wrap(
+                    "The only argument a deprecation attribute can have after its \
+message is the version it was deprecated in.",
+                ),
+                hint: Some("Use `since:` followed by a version string.".into()),
+                label_text: "I was expecting `since` here".into(),
+                extra_labels: vec![],
+            },
+
             ParseErrorType::ExpectedFunctionDefinition => ParseErrorDetails {
                 text: "".into(),
                 hint: None,
@@ -434,7 +474,9 @@ utf16_codepoint, utf32_codepoint, signed, unsigned, big, little, native, size, u
                 let found = match token {
                     Token::Int { .. } => "an Int".to_string(),
                     Token::Float { .. } => "a Float".to_string(),
-                    Token::String { .. } => "a String".to_string(),
+                    Token::String { .. } | Token::InterpolatedString { .. } => {
+                        "a String".to_string()
+                    }
                     Token::CommentDoc { .. } => "a comment".to_string(),
                     Token::DiscardName { .. } => "a discard name".to_string(),
                     Token::Name { .. } | Token::UpName { .. } => "a name".to_string(),
@@ -481,6 +523,7 @@ utf16_codepoint, utf32_codepoint, signed, unsigned, big, little, native, size, u
                     | Token::LArrow
                     | Token::DotDot
                     | Token::At
+                    | Token::Semicolon
                     | Token::EndOfFile
                     | Token::CommentNormal
                     | Token::CommentModule
@@ -645,23 +688,6 @@ utf16_codepoint, utf32_codepoint, signed, unsigned, big, little, native, size, u
                 extra_labels: vec![],
             },
 
-            ParseErrorType::IfExpression => ParseErrorDetails {
-                text: [
-                    "If you want to write a conditional expression you can use a `case`:",
-                    "",
-                    "    case condition {",
-                    "      True -> todo",
-                    "      False -> todo",
-                    "    }",
-                    "",
-                    "See: https://tour.gleam.run/flow-control/case-expressions/",
-                ]
-                .join("\n"),
-                hint: None,
-                label_text: "Gleam doesn't have if expressions".into(),
-                extra_labels: vec![],
-            },
-
             ParseErrorType::ConstantRecordConstructorNoArguments => ParseErrorDetails {
                 text: "A record must be passed arguments when constructed.".into(),
                 hint: None,
@@ -683,12 +709,16 @@ utf16_codepoint, utf32_codepoint, signed, unsigned, big, little, native, size, u
                 extra_labels: vec![],
             },
 
-            ParseErrorType::IncorrectImportModuleSeparator { module, item } => ParseErrorDetails {
+            ParseErrorType::IncorrectImportModuleSeparator {
+                module,
+                item,
+                replacement,
+            } => ParseErrorDetails {
                 text: [
                     "Perhaps you meant one of:".into(),
                     "".into(),
-                    format!("    import {module}/{item}"),
-                    format!("    import {module}.{{item}}"),
+                    format!("    import {}", replacement.text),
+                    format!("    import {module}.{{{item}}}"),
                 ]
                 .join("\n"),
                 hint: None,
@@ -780,6 +810,58 @@ See: https://tour.gleam.run/data-types/generic-custom-types/"
                     extra_labels: vec![],
                 }
             }
+
+            ParseErrorType::FatArrowInCaseClause => ParseErrorDetails {
+                text: "Case clauses use `->` to separate patterns from the clause body.".into(),
+                hint: Some("Replace `=>` with `->`.".into()),
+                label_text: "I was expecting `->` here".into(),
+                extra_labels: vec![],
+            },
+
+            ParseErrorType::MissingCaseClauseArrow => ParseErrorDetails {
+                text: "Case clauses use `->` to separate patterns from the clause body.".into(),
+                hint: Some("Add a `->` between the patterns and the body.".into()),
+                label_text: "I was expecting `->` here".into(),
+                extra_labels: vec![],
+            },
+
+            ParseErrorType::InvalidDeprecationVersion { version } => ParseErrorDetails {
+                text: format!("`{version}` is not a valid version."),
+                hint: Some("Versions are written like this: `1.4.0`.".into()),
+                label_text: "This is not a valid version".into(),
+                extra_labels: vec![],
+            },
+
+            ParseErrorType::SemicolonNotNeeded { semicolons } => ParseErrorDetails {
+                text: wrap(
+                    "Gleam has no statement terminator: statements are separated \
+by new lines, so semicolons are not needed.",
+                ),
+                hint: Some(if semicolons.len() == 1 {
+                    "Remove it.".into()
+                } else {
+                    "Remove them.".into()
+                }),
+                label_text: if semicolons.len() == 1 {
+                    "Remove this semicolon".into()
+                } else {
+                    "Remove these semicolons".into()
+                },
+                extra_labels: vec![],
+            },
+
+            ParseErrorType::UnclosedBlock { opened } => ParseErrorDetails {
+                text: "".into(),
+                hint: Some("Add a `}` to close it.".into()),
+                label_text: "I was expecting a `}` here".into(),
+                extra_labels: vec![ExtraLabel {
+                    src_info: None,
+                    label: Label {
+                        text: Some("This `{` is never closed".into()),
+                        span: *opened,
+                    },
+                }],
+            },
         }
     }
 }
@@ -806,16 +888,21 @@ impl LexicalError {
                 "This float is missing an exponent",
                 vec!["Hint: Add an exponent or remove the trailing `e`".into()],
             ),
+            LexicalErrorType::ExponentLeadingUnderscore => (
+                "An exponent cannot start with an underscore",
+                vec!["Hint: remove it.".into()],
+            ),
+            LexicalErrorType::ExponentTrailingUnderscore => (
+                "An exponent cannot have a trailing underscore",
+                vec!["Hint: remove it.".into()],
+            ),
+            LexicalErrorType::ExponentDoubleUnderscore => (
+                "An exponent cannot have two underscores in a row",
+                vec!["Hint: remove one of them.".into()],
+            ),
             LexicalErrorType::UnexpectedStringEnd => {
                 ("The string starting here was left open", vec![])
             }
-            LexicalErrorType::UnrecognizedToken { tok } if *tok == ';' => (
-                "Remove this semicolon",
-                vec![
-                    "Hint: Semicolons used to be whitespace and did nothing.".into(),
-                    "You can safely remove them without your program changing.".into(),
-                ],
-            ),
             LexicalErrorType::UnrecognizedToken { tok } if *tok == '\'' => (
                 "Unexpected single quote",
                 vec!["Hint: Strings are written with double quotes.".into()],
@@ -848,6 +935,10 @@ impl LexicalError {
             LexicalErrorType::InvalidUnicodeEscape(InvalidUnicodeEscapeError::InvalidCodepoint) => {
                 ("Invalid Unicode codepoint", vec![])
             }
+            LexicalErrorType::UnterminatedInterpolation => (
+                "This interpolation was left open",
+                vec!["Hint: An interpolation must be closed with `}`.".into()],
+            ),
             LexicalErrorType::InvalidTripleEqual => (
                 "Did you mean `==`?",
                 vec![
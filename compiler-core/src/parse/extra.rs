@@ -36,6 +36,26 @@ impl ModuleExtra {
             || self.module_comments.binary_search_by(cmp).is_ok()
     }
 
+    /// Adds the extra information collected while parsing a separate part of
+    /// the same source, such as the expression of a string interpolation,
+    /// keeping everything sorted by position.
+    pub(crate) fn merge(&mut self, other: ModuleExtra) {
+        let ModuleExtra {
+            module_comments,
+            doc_comments,
+            comments,
+            empty_lines,
+            new_lines,
+            trailing_commas,
+        } = other;
+        merge_sorted_spans(&mut self.module_comments, module_comments);
+        merge_sorted_spans(&mut self.doc_comments, doc_comments);
+        merge_sorted_spans(&mut self.comments, comments);
+        merge_sorted_positions(&mut self.empty_lines, empty_lines);
+        merge_sorted_positions(&mut self.new_lines, new_lines);
+        merge_sorted_positions(&mut self.trailing_commas, trailing_commas);
+    }
+
     pub(crate) fn has_comment_between(&self, start: u32, end: u32) -> bool {
         self.first_comment_between(start, end).is_some()
     }
@@ -56,6 +76,20 @@ impl ModuleExtra {
     }
 }
 
+fn merge_sorted_spans(spans: &mut Vec<SrcSpan>, other: Vec<SrcSpan>) {
+    if !other.is_empty() {
+        spans.extend(other);
+        spans.sort_by_key(|span| span.start);
+    }
+}
+
+fn merge_sorted_positions(positions: &mut Vec<u32>, other: Vec<u32>) {
+    if !other.is_empty() {
+        positions.extend(other);
+        positions.sort_unstable();
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Comment<'a> {
     pub start: u32,
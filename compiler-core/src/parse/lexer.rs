@@ -3,7 +3,7 @@ use ecow::EcoString;
 use crate::ast::SrcSpan;
 use crate::parse::LiteralFloatValue;
 use crate::parse::error::{LexicalError, LexicalErrorType};
-use crate::parse::token::Token;
+use crate::parse::token::{StringPart, Token};
 use std::char;
 
 use super::error::InvalidUnicodeEscapeError;
@@ -12,6 +12,13 @@ use super::error::InvalidUnicodeEscapeError;
 pub struct Lexer<T: Iterator<Item = (u32, char)>> {
     chars: T,
     pending: Vec<Spanned>,
+    /// Errors that are yet to be reported. When the lexer can recover from an
+    /// error it lexes a token in place of the invalid code, and the error is
+    /// reported before that token.
+    errors: Vec<LexicalError>,
+    /// Set once the lexer runs into an error it cannot recover from, after
+    /// which no more tokens are produced.
+    halted: bool,
     chr0: Option<char>,
     chr1: Option<char>,
     loc0: u32,
@@ -119,6 +126,8 @@ where
         let mut lxr = Lexer {
             chars: input,
             pending: Vec::new(),
+            errors: Vec::new(),
+            halted: false,
             chr0: None,
             chr1: None,
             loc0: 0,
@@ -133,10 +142,18 @@ where
     // This function is used by the iterator implementation.
     fn inner_next(&mut self) -> LexResult {
         // top loop, keep on processing, until we have something pending.
-        while self.pending.is_empty() {
-            self.consume_normal()?;
+        while self.pending.is_empty() && self.errors.is_empty() {
+            if let Err(error) = self.consume_normal() {
+                // Any other error leaves us in the middle of a token with no
+                // way of telling where the next one starts.
+                self.errors.push(error);
+                self.halted = true;
+            }
         }
 
+        if !self.errors.is_empty() {
+            return Err(self.errors.remove(0));
+        }
         Ok(self.pending.remove(0))
     }
 
@@ -440,6 +457,9 @@ where
             '#' => {
                 self.eat_single_char(Token::Hash);
             }
+            ';' => {
+                self.eat_single_char(Token::Semicolon);
+            }
             '\n' | ' ' | '\t' | '\x0C' => {
                 let tok_start = self.get_pos();
                 let _ = self.next_char();
@@ -530,16 +550,20 @@ where
 
         if Some('_') == self.chr0 {
             let location = self.get_pos();
-            Err(LexicalError {
+            self.errors.push(LexicalError {
                 error: LexicalErrorType::NumTrailingUnderscore,
                 location: SrcSpan {
                     start: location,
                     end: location,
                 },
-            })
-        } else {
-            Ok(num)
+            });
+            // The number is still usable without the underscores, so we skip
+            // them and carry on lexing.
+            while Some('_') == self.chr0 {
+                let _ = self.next_char();
+            }
         }
+        Ok(num)
     }
 
     // Lex a hex/octal/decimal/binary number without a decimal point.
@@ -554,16 +578,22 @@ where
                     end: location,
                 },
             })
-        } else if radix < 16 && Lexer::<T>::is_digit_of_radix(self.chr0, 16) {
-            let location = self.get_pos();
-            Err(LexicalError {
-                error: LexicalErrorType::DigitOutOfRadix,
-                location: SrcSpan {
-                    start: location,
-                    end: location,
-                },
-            })
         } else {
+            if radix < 16 && Lexer::<T>::is_digit_of_radix(self.chr0, 16) {
+                let location = self.get_pos();
+                self.errors.push(LexicalError {
+                    error: LexicalErrorType::DigitOutOfRadix,
+                    location: SrcSpan {
+                        start: location,
+                        end: location,
+                    },
+                });
+                // The digits lexed so far still make a valid number, so the
+                // rest of them are skipped and lexing carries on.
+                while Lexer::<T>::is_digit_of_radix(self.chr0, 16) || self.chr0 == Some('_') {
+                    let _ = self.next_char();
+                }
+            }
             let value = format!("{prefix}{num}");
             let int_value = super::parse_int_value(&value).expect("int value to parse as bigint");
             let end_pos = self.get_pos();
@@ -612,7 +642,7 @@ where
                             .expect("lex_normal_number scientific negative"),
                     );
                 }
-                let exponent_run = self.radix_run(10);
+                let exponent_run = self.exponent_run()?;
                 if exponent_run.is_empty() {
                     return Err(LexicalError {
                         error: LexicalErrorType::MissingExponent,
@@ -660,6 +690,38 @@ where
         Ok(())
     }
 
+    // Consume the digits of a float exponent. Like integers these can be
+    // separated by underscores, but an underscore must sit between two digits.
+    fn exponent_run(&mut self) -> Result<String, LexicalError> {
+        let mut value_text = String::new();
+
+        loop {
+            if let Some(c) = self.take_number(10) {
+                value_text.push(c);
+            } else if self.chr0 == Some('_') {
+                let location = self.get_pos();
+                let error = if value_text.is_empty() {
+                    LexicalErrorType::ExponentLeadingUnderscore
+                } else if self.chr1 == Some('_') {
+                    LexicalErrorType::ExponentDoubleUnderscore
+                } else if Lexer::<T>::is_digit_of_radix(self.chr1, 10) {
+                    value_text.push('_');
+                    let _ = self.next_char();
+                    continue;
+                } else {
+                    LexicalErrorType::ExponentTrailingUnderscore
+                };
+                return Err(LexicalError {
+                    error,
+                    location: SrcSpan::new(location, location + 1),
+                });
+            } else {
+                break;
+            }
+        }
+        Ok(value_text)
+    }
+
     // Consume a sequence of numbers with the given radix,
     // the digits can be decorated with underscores
     // like this: '1_2_3_4' == '1234'
@@ -744,130 +806,32 @@ where
         // advance past the first quote
         let _ = self.next_char();
         let mut string_content = String::new();
+        let mut content_start = self.get_pos();
+        let mut parts = vec![];
 
         loop {
             match self.next_char() {
+                Some('\\') if self.chr0 == Some('{') => {
+                    let interpolation_start = self.get_pos() - 1;
+                    push_literal_part(
+                        &mut parts,
+                        &mut string_content,
+                        SrcSpan::new(content_start, interpolation_start),
+                    );
+                    // advance past the `{`
+                    let _ = self.next_char();
+                    let tokens = self.lex_interpolation(interpolation_start)?;
+                    content_start = self.get_pos();
+                    parts.push(StringPart::Interpolation {
+                        location: SrcSpan::new(interpolation_start, content_start),
+                        tokens,
+                    });
+                }
                 Some('\\') => {
-                    let slash_pos = self.get_pos() - 1;
-                    if let Some(c) = self.chr0 {
-                        match c {
-                            'f' | 'n' | 'r' | 't' | '"' | '\\' => {
-                                let _ = self.next_char();
-                                string_content.push('\\');
-                                string_content.push(c);
-                            }
-                            'u' => {
-                                let _ = self.next_char();
-
-                                if self.chr0 != Some('{') {
-                                    return Err(LexicalError {
-                                        error: LexicalErrorType::InvalidUnicodeEscape(
-                                            InvalidUnicodeEscapeError::MissingOpeningBrace,
-                                        ),
-                                        location: SrcSpan {
-                                            start: self.get_pos() - 1,
-                                            end: self.get_pos(),
-                                        },
-                                    });
-                                }
-
-                                // All digits inside \u{...}.
-                                let mut hex_digits = String::new();
-
-                                loop {
-                                    let _ = self.next_char();
-
-                                    let Some(chr) = self.chr0 else {
-                                        break;
-                                    };
-
-                                    // Don't break early when we've reached 6 digits to ensure a
-                                    // useful error message
-                                    if chr == '}' {
-                                        break;
-                                    }
-
-                                    hex_digits.push(chr);
-
-                                    if !chr.is_ascii_hexdigit() {
-                                        return Err(LexicalError {
-                                            error: LexicalErrorType::InvalidUnicodeEscape(
-                                                InvalidUnicodeEscapeError::ExpectedHexDigitOrCloseBrace,
-                                            ),
-                                            location: SrcSpan {
-                                                start: self.get_pos(),
-                                                end: self.get_pos() + 1,
-                                            },
-                                        });
-                                    }
-                                }
-
-                                if self.chr0 != Some('}') {
-                                    return Err(LexicalError {
-                                        error: LexicalErrorType::InvalidUnicodeEscape(
-                                            InvalidUnicodeEscapeError::ExpectedHexDigitOrCloseBrace,
-                                        ),
-                                        location: SrcSpan {
-                                            start: self.get_pos() - 1,
-                                            end: self.get_pos(),
-                                        },
-                                    });
-                                }
-
-                                let _ = self.next_char();
-
-                                if !(1..=6).contains(&hex_digits.len()) {
-                                    return Err(LexicalError {
-                                        error: LexicalErrorType::InvalidUnicodeEscape(
-                                            InvalidUnicodeEscapeError::InvalidNumberOfHexDigits,
-                                        ),
-                                        location: SrcSpan {
-                                            start: slash_pos,
-                                            end: self.get_pos(),
-                                        },
-                                    });
-                                }
-
-                                // Checks for i >= 0x110000 || (i >= 0xD800 && i < 0xE000),
-                                // where i is the unicode codepoint.
-                                if char::from_u32(u32::from_str_radix(&hex_digits, 16).expect(
-                                    "Cannot parse codepoint number in Unicode escape sequence",
-                                ))
-                                .is_none()
-                                {
-                                    return Err(LexicalError {
-                                        error: LexicalErrorType::InvalidUnicodeEscape(
-                                            InvalidUnicodeEscapeError::InvalidCodepoint,
-                                        ),
-                                        location: SrcSpan {
-                                            start: slash_pos,
-                                            end: self.get_pos(),
-                                        },
-                                    });
-                                }
-
-                                string_content.push_str("\\u{");
-                                string_content.push_str(&hex_digits);
-                                string_content.push('}');
-                            }
-                            _ => {
-                                return Err(LexicalError {
-                                    error: LexicalErrorType::BadStringEscape,
-                                    location: SrcSpan {
-                                        start: slash_pos,
-                                        end: slash_pos + 1,
-                                    },
-                                });
-                            }
-                        }
-                    } else {
-                        return Err(LexicalError {
-                            error: LexicalErrorType::BadStringEscape,
-                            location: SrcSpan {
-                                start: slash_pos,
-                                end: slash_pos,
-                            },
-                        });
+                    // An invalid escape sequence doesn't stop us from lexing
+                    // the rest of the string.
+                    if let Err(error) = self.lex_escape_sequence(&mut string_content) {
+                        self.errors.push(error);
                     }
                 }
                 Some('"') => break,
@@ -885,11 +849,183 @@ where
         }
         let end_pos = self.get_pos();
 
-        let tok = Token::String {
-            value: string_content.into(),
+        if parts.is_empty() {
+            let tok = Token::String {
+                value: string_content.into(),
+            };
+            return Ok((start_pos, tok, end_pos));
+        }
+
+        push_literal_part(
+            &mut parts,
+            &mut string_content,
+            SrcSpan::new(content_start, end_pos - 1),
+        );
+        Ok((start_pos, Token::InterpolatedString { parts }, end_pos))
+    }
+
+    // Lex the tokens of an interpolated expression, this function is entered
+    // after the `\{` and consumes everything up to its closing `}`.
+    fn lex_interpolation(&mut self, start: u32) -> Result<Vec<Spanned>, LexicalError> {
+        // Any token that was already lexed comes after the whole string, so it
+        // is put aside while the interpolation is being lexed.
+        let pending = std::mem::take(&mut self.pending);
+        let mut tokens = vec![];
+        let mut depth = 0;
+        let result = loop {
+            if self.pending.is_empty() {
+                if let Err(error) = self.consume_normal() {
+                    break Err(error);
+                }
+                continue;
+            }
+
+            let (token_start, token, token_end) = self.pending.remove(0);
+            match token {
+                Token::RightBrace if depth == 0 => break Ok(()),
+                Token::RightBrace => depth -= 1,
+                Token::LeftBrace => depth += 1,
+                // An interpolation can span multiple lines, so it's only left
+                // open if the file ends before its closing brace.
+                Token::EndOfFile => {
+                    break Err(LexicalError {
+                        error: LexicalErrorType::UnterminatedInterpolation,
+                        location: SrcSpan::new(start, start + 2),
+                    });
+                }
+                _ => (),
+            }
+            tokens.push((token_start, token, token_end));
         };
+        self.pending = pending;
+        result.map(|()| tokens)
+    }
 
-        Ok((start_pos, tok, end_pos))
+    // Lex an escape sequence in a string, this function is entered after the
+    // backslash.
+    fn lex_escape_sequence(&mut self, string_content: &mut String) -> Result<(), LexicalError> {
+        let slash_pos = self.get_pos() - 1;
+        let Some(c) = self.chr0 else {
+            return Err(LexicalError {
+                error: LexicalErrorType::BadStringEscape,
+                location: SrcSpan {
+                    start: slash_pos,
+                    end: slash_pos,
+                },
+            });
+        };
+        match c {
+            'f' | 'n' | 'r' | 't' | '"' | '\\' => {
+                let _ = self.next_char();
+                string_content.push('\\');
+                string_content.push(c);
+            }
+            'u' => {
+                let _ = self.next_char();
+
+                if self.chr0 != Some('{') {
+                    return Err(LexicalError {
+                        error: LexicalErrorType::InvalidUnicodeEscape(
+                            InvalidUnicodeEscapeError::MissingOpeningBrace,
+                        ),
+                        location: SrcSpan {
+                            start: self.get_pos() - 1,
+                            end: self.get_pos(),
+                        },
+                    });
+                }
+
+                // All digits inside \u{...}.
+                let mut hex_digits = String::new();
+
+                loop {
+                    let _ = self.next_char();
+
+                    let Some(chr) = self.chr0 else {
+                        break;
+                    };
+
+                    // Don't break early when we've reached 6 digits to ensure a
+                    // useful error message
+                    if chr == '}' {
+                        break;
+                    }
+
+                    hex_digits.push(chr);
+
+                    if !chr.is_ascii_hexdigit() {
+                        return Err(LexicalError {
+                            error: LexicalErrorType::InvalidUnicodeEscape(
+                                InvalidUnicodeEscapeError::ExpectedHexDigitOrCloseBrace,
+                            ),
+                            location: SrcSpan {
+                                start: self.get_pos(),
+                                end: self.get_pos() + 1,
+                            },
+                        });
+                    }
+                }
+
+                if self.chr0 != Some('}') {
+                    return Err(LexicalError {
+                        error: LexicalErrorType::InvalidUnicodeEscape(
+                            InvalidUnicodeEscapeError::ExpectedHexDigitOrCloseBrace,
+                        ),
+                        location: SrcSpan {
+                            start: self.get_pos() - 1,
+                            end: self.get_pos(),
+                        },
+                    });
+                }
+
+                let _ = self.next_char();
+
+                if !(1..=6).contains(&hex_digits.len()) {
+                    return Err(LexicalError {
+                        error: LexicalErrorType::InvalidUnicodeEscape(
+                            InvalidUnicodeEscapeError::InvalidNumberOfHexDigits,
+                        ),
+                        location: SrcSpan {
+                            start: slash_pos,
+                            end: self.get_pos(),
+                        },
+                    });
+                }
+
+                // Checks for i >= 0x110000 || (i >= 0xD800 && i < 0xE000),
+                // where i is the unicode codepoint.
+                if char::from_u32(
+                    u32::from_str_radix(&hex_digits, 16)
+                        .expect("Cannot parse codepoint number in Unicode escape sequence"),
+                )
+                .is_none()
+                {
+                    return Err(LexicalError {
+                        error: LexicalErrorType::InvalidUnicodeEscape(
+                            InvalidUnicodeEscapeError::InvalidCodepoint,
+                        ),
+                        location: SrcSpan {
+                            start: slash_pos,
+                            end: self.get_pos(),
+                        },
+                    });
+                }
+
+                string_content.push_str("\\u{");
+                string_content.push_str(&hex_digits);
+                string_content.push('}');
+            }
+            _ => {
+                return Err(LexicalError {
+                    error: LexicalErrorType::BadStringEscape,
+                    location: SrcSpan {
+                        start: slash_pos,
+                        end: slash_pos + 1,
+                    },
+                });
+            }
+        }
+        Ok(())
     }
 
     fn is_name_start(&self, c: char) -> bool {
@@ -952,6 +1088,15 @@ where
     }
 }
 
+fn push_literal_part(parts: &mut Vec<StringPart>, content: &mut String, location: SrcSpan) {
+    if !content.is_empty() {
+        parts.push(StringPart::Literal {
+            location,
+            value: std::mem::take(content).into(),
+        });
+    }
+}
+
 impl<T> Iterator for Lexer<T>
 where
     T: Iterator<Item = (u32, char)>,
@@ -959,6 +1104,9 @@ where
     type Item = LexResult;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.halted && self.errors.is_empty() {
+            return None;
+        }
         let token = self.inner_next();
 
         match token {
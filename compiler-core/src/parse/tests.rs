@@ -1,9 +1,11 @@
-use crate::ast::SrcSpan;
+use crate::ast::{InterpolatedStringSegment, SrcSpan, Statement, UntypedExpr};
+use crate::parse::ModuleParseError;
 use crate::parse::error::{
     InvalidUnicodeEscapeError, LexicalError, LexicalErrorType, ParseError, ParseErrorType,
+    Replacement,
 };
 use crate::parse::lexer::make_tokenizer;
-use crate::parse::token::Token;
+use crate::parse::token::{StringPart, Token};
 use crate::warning::WarningEmitter;
 use camino::Utf8PathBuf;
 
@@ -50,14 +52,16 @@ macro_rules! assert_parse {
     };
 }
 
+pub fn parse_module_errors(src: &str) -> ModuleParseError {
+    crate::parse::parse_module(Utf8PathBuf::from("test/path"), src, &WarningEmitter::null())
+        .expect_err("should not parse")
+}
+
 pub fn expect_module_error(src: &str) -> String {
-    let result =
-        crate::parse::parse_module(Utf8PathBuf::from("test/path"), src, &WarningEmitter::null())
-            .expect_err("should not parse");
     let error = crate::error::Error::Parse {
         src: src.into(),
         path: Utf8PathBuf::from("/src/parse/error.gleam"),
-        error: Box::new(result),
+        errors: parse_module_errors(src).errors,
     };
     error.pretty_string()
 }
@@ -67,7 +71,7 @@ pub fn expect_error(src: &str) -> String {
     let error = crate::error::Error::Parse {
         src: src.into(),
         path: Utf8PathBuf::from("/src/parse/error.gleam"),
-        error: Box::new(result),
+        errors: vec1::vec1![result],
     };
     error.pretty_string()
 }
@@ -160,6 +164,24 @@ fn string_bad_character_escape_leading_backslash() {
     );
 }
 
+#[test]
+fn multiple_lex_errors_are_all_reported() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  let a = "\g"
+  let b = 0b012
+  let c = 1_000_
+}
+"#
+    );
+}
+
+#[test]
+fn lex_errors_before_an_unrecoverable_one_are_reported() {
+    assert_module_error!(r#"const a = "\g" const b = "wibble"#);
+}
+
 #[test]
 fn string_freestanding_unicode_escape_sequence() {
     assert_error!(
@@ -646,6 +668,77 @@ fn semicolons() {
     assert_error!(r#"{ 2 + 3; - -5; }"#);
 }
 
+#[test]
+fn semicolon_after_let() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  let x = 1;
+  x
+}
+"#
+    );
+}
+
+#[test]
+fn semicolons_at_end_of_block() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  let x = 1
+  x;;
+}
+"#
+    );
+}
+
+#[test]
+fn semicolon_after_import() {
+    assert_module_error!(
+        r#"
+import gleam/io;
+"#
+    );
+}
+
+#[test]
+fn semicolon_after_case_clause() {
+    assert_module_error!(
+        r#"
+pub fn main(x) {
+  case x {
+    1 -> 2;
+    _ -> 3
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn semicolon_in_call_arguments() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  wibble(1;)
+}
+"#
+    );
+}
+
+#[test]
+fn consecutive_semicolons_are_reported_together() {
+    assert_error!(
+        "1; ;",
+        ParseError {
+            error: ParseErrorType::SemicolonNotNeeded {
+                semicolons: vec![SrcSpan::new(1, 2), SrcSpan::new(3, 4)],
+            },
+            location: SrcSpan::new(1, 4),
+        }
+    );
+}
+
 #[test]
 fn bare_expression() {
     assert_parse!(r#"1"#);
@@ -782,6 +875,42 @@ pub fn main() -> Nil {
     );
 }
 
+#[test]
+fn deprecation_with_since() {
+    assert_parse_module!(
+        r#"
+@deprecated("Use wobble instead", since: "1.4.0")
+pub fn wibble() -> Nil {
+  Nil
+}
+"#
+    );
+}
+
+#[test]
+fn deprecation_with_invalid_since() {
+    assert_module_error!(
+        r#"
+@deprecated("Use wobble instead", since: "one point four")
+pub fn wibble() -> Nil {
+  Nil
+}
+"#
+    );
+}
+
+#[test]
+fn deprecation_with_unknown_argument() {
+    assert_module_error!(
+        r#"
+@deprecated("Use wobble instead", until: "2.0.0")
+pub fn wibble() -> Nil {
+  Nil
+}
+"#
+    );
+}
+
 #[test]
 fn multiple_internal_attributes() {
     assert_module_error!(
@@ -795,6 +924,41 @@ pub fn main() -> Nil {
     );
 }
 
+#[test]
+fn must_use_attribute() {
+    assert_parse_module!(
+        r#"
+@must_use
+pub fn wibble() -> Nil {
+  Nil
+}
+"#
+    );
+}
+
+#[test]
+fn multiple_must_use_attributes() {
+    assert_module_error!(
+        r#"
+@must_use
+@must_use
+pub fn main() -> Nil {
+  Nil
+}
+"#
+    );
+}
+
+#[test]
+fn must_use_attribute_on_constant() {
+    assert_module_error!(
+        r#"
+@must_use
+pub const wibble = 1
+"#
+    );
+}
+
 #[test]
 fn attributes_with_no_definition() {
     assert_module_error!(
@@ -916,6 +1080,17 @@ fn add(x, y) {
     );
 }
 
+#[test]
+fn capture_with_multiple_holes() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  add(1, _, _)
+}
+"#
+    );
+}
+
 #[test]
 fn list_spread_with_no_tail_in_the_middle_of_a_list() {
     assert_module_error!(
@@ -1158,6 +1333,186 @@ fn main() {
     );
 }
 
+#[test]
+fn case_clause_fat_arrow() {
+    assert_module_error!(
+        "
+fn main() {
+    case 1 {
+      1 => 2
+    }
+}
+"
+    );
+}
+
+#[test]
+fn case_clauses_fat_arrows() {
+    assert_module_error!(
+        "
+fn main() {
+    case 1 {
+      1 => 2
+      2 => 3
+      _ => 4
+    }
+}
+"
+    );
+}
+
+#[test]
+fn case_clauses_fat_arrows_are_all_reported() {
+    let src = "
+fn main() {
+    case 1 {
+      1 => 2
+      _ => 3
+    }
+}
+";
+    let errors = parse_module_errors(src).errors;
+    assert_eq!(
+        errors.to_vec(),
+        vec![
+            ParseError {
+                error: ParseErrorType::FatArrowInCaseClause,
+                location: SrcSpan::new(34, 36),
+            },
+            ParseError {
+                error: ParseErrorType::FatArrowInCaseClause,
+                location: SrcSpan::new(47, 49),
+            },
+        ]
+    );
+}
+
+#[test]
+fn case_clause_missing_arrow() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  case 1 { 1 2 }
+}
+"#
+    );
+}
+
+#[test]
+fn case_clause_missing_arrow_in_middle_clause() {
+    assert_module_error!(
+        r#"
+pub fn main(x) {
+  case x {
+    1 -> "one"
+    2 "two"
+    _ -> "many"
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn case_clause_missing_arrow_after_guard() {
+    assert_module_error!(
+        r#"
+pub fn main(x) {
+  case x {
+    _ if x > 1 True
+    _ -> False
+  }
+}
+"#
+    );
+}
+
+fn recovered_case_clauses(src: &str) -> (usize, Vec<ParseError>) {
+    let error = parse_module_errors(src);
+    let Some(crate::ast::Definition::Function(function)) = error
+        .parsed
+        .module
+        .definitions
+        .first()
+        .map(|definition| &definition.definition)
+    else {
+        panic!("expected a function")
+    };
+    let Some(crate::ast::Statement::Expression(crate::ast::UntypedExpr::Case { clauses, .. })) =
+        function.body.first()
+    else {
+        panic!("expected a case expression")
+    };
+    (clauses.as_ref().map_or(0, Vec::len), error.errors.to_vec())
+}
+
+#[test]
+fn case_clause_missing_arrow_is_recovered() {
+    let (clauses, errors) = recovered_case_clauses(
+        "
+fn main(x) {
+  case x {
+    1 2
+    _ -> 3
+  }
+}
+",
+    );
+    assert_eq!(clauses, 2);
+    assert_eq!(
+        errors,
+        vec![ParseError {
+            error: ParseErrorType::MissingCaseClauseArrow,
+            location: SrcSpan::new(30, 31),
+        }]
+    );
+}
+
+#[test]
+fn case_clause_missing_arrow_in_middle_clause_is_recovered() {
+    let (clauses, errors) = recovered_case_clauses(
+        "
+fn main(x) {
+  case x {
+    1 -> 1
+    2 2
+    3 3
+    _ -> 4
+  }
+}
+",
+    );
+    assert_eq!(clauses, 4);
+    assert_eq!(
+        errors,
+        vec![
+            ParseError {
+                error: ParseErrorType::MissingCaseClauseArrow,
+                location: SrcSpan::new(41, 42),
+            },
+            ParseError {
+                error: ParseErrorType::MissingCaseClauseArrow,
+                location: SrcSpan::new(49, 50),
+            },
+        ]
+    );
+}
+
+#[test]
+fn case_clause_body_on_next_line_is_not_a_missing_arrow() {
+    assert_module_error!(
+        r#"
+pub fn main(x) {
+  case x {
+    1 -> wibble
+      wobble
+    _ -> 2
+  }
+}
+"#
+    );
+}
+
 #[test]
 fn case_alternative_clause_no_subject() {
     assert_module_error!(
@@ -1408,6 +1763,124 @@ fn newline_tokens() {
     );
 }
 
+#[test]
+fn interpolated_string_tokens() {
+    assert_eq!(
+        make_tokenizer(r#""a\{b}c""#).collect_vec(),
+        [Ok((
+            0,
+            Token::InterpolatedString {
+                parts: vec![
+                    StringPart::Literal {
+                        location: SrcSpan::new(1, 2),
+                        value: "a".into()
+                    },
+                    StringPart::Interpolation {
+                        location: SrcSpan::new(2, 6),
+                        tokens: vec![(4, Token::Name { name: "b".into() }, 5)]
+                    },
+                    StringPart::Literal {
+                        location: SrcSpan::new(6, 7),
+                        value: "c".into()
+                    },
+                ]
+            },
+            8
+        ))]
+    );
+}
+
+#[test]
+fn interpolated_string_with_nested_braces_and_strings() {
+    assert_eq!(
+        make_tokenizer(r#""\{{ "}" }}""#).collect_vec(),
+        [Ok((
+            0,
+            Token::InterpolatedString {
+                parts: vec![StringPart::Interpolation {
+                    location: SrcSpan::new(1, 11),
+                    tokens: vec![
+                        (3, Token::LeftBrace, 4),
+                        (5, Token::String { value: "}".into() }, 8),
+                        (9, Token::RightBrace, 10),
+                    ]
+                }]
+            },
+            12
+        ))]
+    );
+}
+
+#[test]
+fn interpolated_string() {
+    let statements = crate::parse::parse_statement_sequence(r#""a\{b}c""#).expect("should parse");
+    assert_eq!(
+        statements.first(),
+        &Statement::Expression(UntypedExpr::InterpolatedString {
+            location: SrcSpan::new(0, 8),
+            segments: vec![
+                InterpolatedStringSegment::Literal {
+                    location: SrcSpan::new(1, 2),
+                    value: "a".into()
+                },
+                InterpolatedStringSegment::Expression {
+                    location: SrcSpan::new(2, 6),
+                    expression: UntypedExpr::Var {
+                        location: SrcSpan::new(4, 5),
+                        name: "b".into()
+                    }
+                },
+                InterpolatedStringSegment::Literal {
+                    location: SrcSpan::new(6, 7),
+                    value: "c".into()
+                },
+            ]
+        })
+    );
+}
+
+#[test]
+fn interpolated_string_with_two_expressions() {
+    assert_error!(
+        r#""\{a b}""#,
+        ParseError {
+            error: ParseErrorType::UnexpectedToken {
+                token: Token::Name { name: "b".into() },
+                expected: vec!["`}`".into()],
+                hint: None,
+            },
+            location: SrcSpan { start: 5, end: 6 },
+        }
+    );
+}
+
+#[test]
+fn interpolated_string_with_no_expression() {
+    assert_error!(
+        r#""\{}""#,
+        ParseError {
+            error: ParseErrorType::ExpectedExpr,
+            location: SrcSpan { start: 1, end: 4 },
+        }
+    );
+}
+
+#[test]
+fn unterminated_interpolation() {
+    assert_error!(
+        "\"\\{a",
+        ParseError {
+            error: ParseErrorType::LexError {
+                error: LexicalError {
+                    error: LexicalErrorType::UnterminatedInterpolation,
+                    location: SrcSpan { start: 1, end: 3 },
+                }
+            },
+            location: SrcSpan { start: 1, end: 3 },
+        }
+    );
+}
+
 // https://github.com/gleam-lang/gleam/issues/1756
 #[test]
 fn arithmetic_in_guards() {
@@ -1438,6 +1911,15 @@ const no_cute_bee = \"cute\" <>
     );
 }
 
+#[test]
+fn const_arithmetic_naked_right() {
+    assert_module_error!(
+        "
+const size = 4 *
+"
+    );
+}
+
 #[test]
 fn function_call_in_case_clause_guard() {
     assert_error!(
@@ -1740,13 +2222,58 @@ type Wibble {
 }
 
 #[test]
-fn if_like_expression() {
+fn if_expression() {
+    assert_parse!("if wibble { wobble }");
+}
+
+#[test]
+fn if_else_expression() {
+    assert_parse!("if wibble { wobble } else { wubble }");
+}
+
+#[test]
+fn if_else_if_expression() {
+    assert_parse!("if a { 1 } else if b { 2 } else { 3 }");
+}
+
+#[test]
+fn let_else_statement() {
+    assert_parse!("let Ok(x) = wibble else { panic }\nx");
+}
+
+#[test]
+fn let_else_without_block() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  let Ok(x) = wibble else panic
+  x
+}
+"#
+    );
+}
+
+#[test]
+fn subjectless_case_expression() {
+    assert_parse!("case { a -> 1 _ -> 2 }");
+}
+
+#[test]
+fn case_with_block_subject() {
+    assert_parse!("case { 1 } { _ -> 2 }");
+}
+
+#[test]
+fn case_with_block_subject_followed_by_other_subjects() {
+    assert_parse!("case { 1 } + 1, 2 { _, _ -> 3 }");
+}
+
+#[test]
+fn else_without_block() {
     assert_module_error!(
         r#"
 pub fn main() {
-  let a = if wibble {
-    wobble
-  }
+  if wibble { wobble } else wubble
 }
 "#
     );
@@ -1812,6 +2339,31 @@ fn float_empty_exponent() {
     assert_error!("1.32e");
 }
 
+#[test]
+fn float_exponent_with_underscores() {
+    assert_parse!("1.0e1_0");
+}
+
+#[test]
+fn float_exponent_leading_underscore() {
+    assert_error!("1.0e_1");
+}
+
+#[test]
+fn float_negative_exponent_leading_underscore() {
+    assert_error!("1.0e-_1");
+}
+
+#[test]
+fn float_exponent_trailing_underscore() {
+    assert_error!("1.0e1_");
+}
+
+#[test]
+fn float_exponent_double_underscore() {
+    assert_error!("1.0e1__0");
+}
+
 #[test]
 fn multiple_deprecation_attribute_on_type_variant() {
     assert_module_error!(
@@ -1839,7 +2391,7 @@ type Wibble {
 }
 
 #[test]
-fn internal_attribute_on_type_variant() {
+fn internal_attribute_on_private_type_variant() {
     assert_module_error!(
         r#"
 type Wibble {
@@ -1850,6 +2402,18 @@ type Wibble {
     );
 }
 
+#[test]
+fn must_use_attribute_on_type_variant() {
+    assert_module_error!(
+        r#"
+type Wibble {
+    @must_use
+    Wibble1
+}
+"#
+    );
+}
+
 #[test]
 fn external_attribute_on_type_variant() {
     assert_module_error!(
@@ -1917,6 +2481,38 @@ fn special_error_for_pythonic_neste_import() {
     assert_module_error!("import one.two.three");
 }
 
+fn pythonic_import_replacement(src: &str) -> Replacement {
+    let error = parse_module_errors(src).errors.split_off_first().0;
+    let ParseErrorType::IncorrectImportModuleSeparator { replacement, .. } = error.error else {
+        panic!("expected a pythonic import error, got {error:?}")
+    };
+    replacement
+}
+
+#[test]
+fn pythonic_import_replacement_is_slash_separated_path() {
+    let replacement = pythonic_import_replacement("import gleam.io");
+    assert_eq!(
+        replacement,
+        Replacement {
+            location: SrcSpan::new(7, 15),
+            text: "gleam/io".into(),
+        }
+    );
+}
+
+#[test]
+fn pythonic_nested_import_replacement_is_slash_separated_path() {
+    let replacement = pythonic_import_replacement("import one.two.three as four");
+    assert_eq!(
+        replacement,
+        Replacement {
+            location: SrcSpan::new(7, 20),
+            text: "one/two/three".into(),
+        }
+    );
+}
+
 #[test]
 fn doesnt_issue_special_error_for_pythonic_import_if_slash() {
     assert_module_error!("import one/two.three");
@@ -2090,3 +2686,184 @@ const local_const = other.Record(..other.base, field: value)
 "#
     );
 }
+
+#[test]
+fn const_record_label_shorthand() {
+    assert_parse_module!(
+        r#"
+type Person {
+  Person(name: String, age: Int)
+}
+
+const name = "Alice"
+const alice = Person(name:, age: 30)
+"#
+    );
+}
+
+#[test]
+fn const_record_update_label_shorthand() {
+    assert_parse_module!(
+        r#"
+type Person {
+  Person(name: String, age: Int)
+}
+
+const name = "Bob"
+const alice = Person("Alice", 30)
+const bob = Person(..alice, name:)
+"#
+    );
+}
+
+fn partially_parsed_function_names(src: &str) -> (Vec<EcoString>, Vec<ParseErrorType>) {
+    let error = parse_module_errors(src);
+    let names = error
+        .parsed
+        .module
+        .definitions
+        .iter()
+        .filter_map(|definition| {
+            let crate::ast::Definition::Function(function) = &definition.definition else {
+                return None;
+            };
+            function.name.as_ref().map(|(_, name)| name.clone())
+        })
+        .collect_vec();
+    let errors = error
+        .errors
+        .into_iter()
+        .map(|error| error.error)
+        .collect_vec();
+    (names, errors)
+}
+
+#[test]
+fn unclosed_function_body_is_recovered() {
+    let (names, errors) = partially_parsed_function_names(
+        "
+pub fn main() {
+  1
+
+pub fn wobble() {
+  2
+}
+",
+    );
+    assert_eq!(names, vec![EcoString::from("main"), "wobble".into()]);
+    assert_eq!(
+        errors,
+        vec![ParseErrorType::UnclosedBlock {
+            opened: SrcSpan::new(15, 16)
+        }]
+    );
+}
+
+#[test]
+fn unclosed_block_is_attributed_to_the_outermost_block() {
+    let (names, errors) = partially_parsed_function_names(
+        "
+pub fn main() {
+  let x = {
+    1
+}
+
+fn wobble() {
+  2
+}
+",
+    );
+    assert_eq!(names, vec![EcoString::from("main"), "wobble".into()]);
+    assert_eq!(
+        errors,
+        vec![ParseErrorType::UnclosedBlock {
+            opened: SrcSpan::new(15, 16)
+        }]
+    );
+}
+
+#[test]
+fn unclosed_case_is_recovered() {
+    let (names, errors) = partially_parsed_function_names(
+        "
+pub fn main() {
+  case 1 {
+    _ -> 2
+
+@deprecated(\"wibble\")
+fn wobble() {
+  2
+}
+",
+    );
+    assert_eq!(names, vec![EcoString::from("main"), "wobble".into()]);
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn unclosed_block_at_end_of_file_is_recovered() {
+    let (names, errors) = partially_parsed_function_names(
+        "
+pub fn main() {
+  1
+}
+
+pub fn wobble() {
+  2",
+    );
+    assert_eq!(names, vec![EcoString::from("main"), "wobble".into()]);
+    assert_eq!(
+        errors,
+        vec![ParseErrorType::UnclosedBlock {
+            opened: SrcSpan::new(40, 41)
+        }]
+    );
+}
+
+#[test]
+fn definitions_before_a_syntax_error_are_kept() {
+    let (names, _) = partially_parsed_function_names(
+        "
+pub fn main() {
+  1
+}
+
+pub fn wobble() {
+  1 +
+}
+",
+    );
+    assert_eq!(names, vec![EcoString::from("main")]);
+}
+
+#[test]
+fn unclosed_function_body() {
+    assert_module_error!(
+        "
+pub fn main() {
+  let x = 1
+  x
+
+pub fn wobble() {
+  2
+}
+"
+    );
+}
+
+#[test]
+fn unclosed_case() {
+    assert_module_error!(
+        "
+pub fn main() {
+  case 1 {
+    _ -> 2
+}
+"
+    );
+}
+
+#[test]
+fn unclosed_block_in_expression() {
+    assert_error!("let x = { 1");
+}
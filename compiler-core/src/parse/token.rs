@@ -3,6 +3,7 @@ use std::fmt;
 
 use ecow::EcoString;
 
+use crate::ast::SrcSpan;
 use crate::parse::LiteralFloatValue;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -27,6 +28,10 @@ pub enum Token {
     String {
         value: EcoString,
     },
+    /// A string with at least one `\{...}` interpolation in it.
+    InterpolatedString {
+        parts: Vec<StringPart>,
+    },
     CommentDoc {
         content: EcoString,
     },
@@ -77,6 +82,7 @@ pub enum Token {
     LArrow,     // '<-'
     DotDot,     // '..'
     At,         // '@'
+    Semicolon,  // ';'
     EndOfFile,
     // Extra
     CommentNormal,
@@ -107,6 +113,19 @@ pub enum Token {
     Use,
 }
 
+/// A piece of an interpolated string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StringPart {
+    /// Text written as it is in the source, escape sequences included.
+    Literal { location: SrcSpan, value: EcoString },
+    /// The tokens of the expression written between `\{` and `}`. The
+    /// location covers the whole interpolation, delimiters included.
+    Interpolation {
+        location: SrcSpan,
+        tokens: Vec<(u32, Token, u32)>,
+    },
+}
+
 impl Token {
     pub fn guard_precedence(&self) -> Option<u8> {
         match self {
@@ -137,6 +156,7 @@ impl Token {
             | Self::Int { .. }
             | Self::Float { .. }
             | Self::String { .. }
+            | Self::InterpolatedString { .. }
             | Self::CommentDoc { .. }
             | Self::LeftParen
             | Self::RightParen
@@ -158,6 +178,7 @@ impl Token {
             | Self::LArrow
             | Self::DotDot
             | Self::At
+            | Self::Semicolon
             | Self::EndOfFile
             | Self::CommentNormal
             | Self::CommentModule
@@ -218,6 +239,7 @@ impl Token {
             | Token::Int { .. }
             | Token::Float { .. }
             | Token::String { .. }
+            | Token::InterpolatedString { .. }
             | Token::CommentDoc { .. }
             | Token::LeftParen
             | Token::RightParen
@@ -261,6 +283,7 @@ impl Token {
             | Token::LArrow
             | Token::DotDot
             | Token::At
+            | Token::Semicolon
             | Token::EndOfFile
             | Token::CommentNormal
             | Token::CommentModule
@@ -284,6 +307,7 @@ impl fmt::Display for Token {
                 float_value: _,
             }
             | Token::String { value } => value.as_str(),
+            Token::InterpolatedString { .. } => "interpolated string",
             Token::AmperAmper => "&&",
             Token::As => "as",
             Token::Assert => "assert",
@@ -314,6 +338,7 @@ impl fmt::Display for Token {
             Token::GreaterEqualDot => ">=.",
             Token::GtGt => ">>",
             Token::Hash => "#",
+            Token::Semicolon => ";",
             Token::If => "if",
             Token::Implement => "implement",
             Token::Import => "import",
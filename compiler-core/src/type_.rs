@@ -722,6 +722,9 @@ pub enum ValueConstructorVariant {
         external_erlang: Option<(EcoString, EcoString)>,
         external_javascript: Option<(EcoString, EcoString)>,
         purity: Purity,
+        /// Whether the function has the `@must_use` attribute, meaning the
+        /// value returned by a call to it should not be discarded.
+        must_use: bool,
     },
 
     /// A constructor for a custom type
@@ -784,6 +787,7 @@ impl ValueConstructorVariant {
                 location: *location,
                 field_map: None,
                 purity: Purity::Impure,
+                must_use: false,
             },
 
             Self::ModuleFn {
@@ -795,6 +799,7 @@ impl ValueConstructorVariant {
                 external_erlang,
                 external_javascript,
                 purity,
+                must_use,
                 ..
             } => ModuleValueConstructor::Fn {
                 name: name.clone(),
@@ -805,6 +810,7 @@ impl ValueConstructorVariant {
                 location: *location,
                 field_map: field_map.clone(),
                 purity: *purity,
+                must_use: *must_use,
             },
         }
     }
@@ -913,6 +919,7 @@ pub enum ModuleValueConstructor {
         field_map: Option<FieldMap>,
         documentation: Option<EcoString>,
         purity: Purity,
+        must_use: bool,
     },
 
     Constant {
@@ -1086,6 +1093,10 @@ pub struct TypeValueConstructor {
     pub name: EcoString,
     pub parameters: Vec<TypeValueConstructorField>,
     pub documentation: Option<EcoString>,
+    /// The publicity the variant was defined with. This is only internal if
+    /// the variant itself has the `@internal` attribute, in which case it
+    /// cannot be constructed or matched on outside of its own package.
+    pub publicity: Publicity,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1390,6 +1401,9 @@ pub enum Deprecation {
     NotDeprecated,
     Deprecated {
         message: EcoString,
+        /// The version the item was deprecated in, if one was given with
+        /// `@deprecated("...", since: "1.4.0")`.
+        since: Option<EcoString>,
     },
 }
 
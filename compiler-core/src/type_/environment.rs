@@ -486,6 +486,37 @@ impl Environment<'_> {
         }
     }
 
+    /// If the custom type variant with the given index is marked as `@internal`
+    /// and defined in a different package, returns the name of that package.
+    /// Such a variant can't be used to construct or match on values outside
+    /// of its own package.
+    ///
+    /// The type can either be the custom type itself or, for variants with
+    /// fields, the type of their constructor function.
+    ///
+    pub fn internal_variant_package(&self, type_: &Type, variant_index: u16) -> Option<&EcoString> {
+        let (module, type_name) = match type_.return_type() {
+            Some(return_type) => return_type.named_type_name()?,
+            None => type_.named_type_name()?,
+        };
+
+        let interface = self.importable_modules.get(&module)?;
+        if interface.package == self.current_package {
+            return None;
+        }
+
+        let variant = interface
+            .types_value_constructors
+            .get(&type_name)?
+            .variants
+            .get(variant_index as usize)?;
+
+        variant
+            .publicity
+            .is_internal()
+            .then_some(&interface.package)
+    }
+
     /// Lookup a value constructor in the current scope.
     ///
     pub fn get_value_constructor(
@@ -336,6 +336,21 @@ pub enum Error {
         location: SrcSpan,
     },
 
+    /// A positional field access like `cat.2` on a record variant that
+    /// doesn't have that many unlabelled fields.
+    OutOfBoundsPositionalField {
+        location: SrcSpan,
+        index: u64,
+        size: usize,
+    },
+
+    /// A positional field access like `pet.0` on a value of a custom type
+    /// with multiple variants, where it's not known which variant it is.
+    PositionalAccessUnknownVariant {
+        location: SrcSpan,
+        type_: Arc<Type>,
+    },
+
     RecordAccessUnknownType {
         location: SrcSpan,
     },
@@ -443,6 +458,17 @@ pub enum Error {
         location: SrcSpan,
     },
 
+    /// The `else` body of a `let else` assignment can be evaluated without
+    /// panicking, so it would need to produce a value for the pattern's
+    /// variables.
+    ///
+    /// ```gleam
+    /// let Ok(a) = something() else { 1 }
+    /// ```
+    LetElseDoesNotDiverge {
+        location: SrcSpan,
+    },
+
     /// Let assignment's pattern does not match all possible values of the type.
     InexhaustiveLetAssignment {
         location: SrcSpan,
@@ -590,6 +616,29 @@ pub enum Error {
         location: SrcSpan,
     },
 
+    /// Occurs when arithmetic in a constant divides by zero, so it can't be
+    /// folded into a literal value.
+    ///
+    /// ```gleam
+    /// const size = 1024 / 0
+    /// ```
+    ///
+    ConstantDivisionByZero {
+        location: SrcSpan,
+    },
+
+    /// Occurs when a value interpolated into a string is not itself a string.
+    ///
+    /// ```gleam
+    /// "There are \{count} items"
+    /// //          ^^^^^ This is an Int
+    /// ```
+    ///
+    InterpolatedValueNotString {
+        location: SrcSpan,
+        given: Arc<Type>,
+    },
+
     /// When the echo keyword is not followed by an expression to be printed.
     /// The only place where echo is allowed to appear on its own is as a step
     /// of a pipeline, otherwise omitting the expression will result in this
@@ -654,6 +703,29 @@ pub enum Error {
         location: SrcSpan,
     },
 
+    /// This happens when a variant marked as `@internal` is used to construct
+    /// or match on a value outside of the package it's defined in.
+    ///
+    /// ```gleam
+    /// // In another package
+    /// pub type Wibble {
+    ///   Wibble
+    ///   @internal
+    ///   Wobble
+    /// }
+    ///
+    /// // In this package
+    /// pub fn main() {
+    ///   wibble.Wobble
+    /// }
+    /// ```
+    ///
+    InternalVariantFromOtherPackage {
+        location: SrcSpan,
+        name: EcoString,
+        package: EcoString,
+    },
+
     SrcImportingDevDependency {
         importing_module: EcoString,
         imported_module: EcoString,
@@ -846,6 +918,13 @@ pub enum Warning {
     UnusedValue {
         location: SrcSpan,
     },
+
+    /// A call to a function with the `@must_use` attribute is used as a
+    /// statement, so the value it returns is discarded.
+    UnusedMustUseValue {
+        location: SrcSpan,
+    },
+
     NoFieldsRecordUpdate {
         location: SrcSpan,
     },
@@ -919,6 +998,7 @@ pub enum Warning {
     DeprecatedItem {
         location: SrcSpan,
         message: EcoString,
+        since: Option<EcoString>,
         layer: Layer,
     },
 
@@ -1004,6 +1084,12 @@ pub enum Warning {
         location: SrcSpan,
     },
 
+    /// The pattern of a `let else` assignment covers all possible values, so
+    /// the `else` body can never run.
+    RedundantLetElse {
+        location: SrcSpan,
+    },
+
     AssertAssignmentOnImpossiblePattern {
         location: SrcSpan,
         reason: AssertImpossiblePattern,
@@ -1171,6 +1257,15 @@ pub enum FeatureKind {
     ExternalCustomType,
     ConstantRecordUpdate,
     ExpressionInSegmentSize,
+    IfExpression,
+    LetElse,
+    ConstantArithmetic,
+    StringInterpolation,
+    SubjectlessCase,
+    PositionalFieldAccess,
+    VariantWithInternalAnnotation,
+    MustUseAnnotation,
+    DeprecationSince,
 }
 
 impl FeatureKind {
@@ -1184,7 +1279,16 @@ impl FeatureKind {
 
             FeatureKind::ArithmeticInGuards => Version::new(1, 3, 0),
 
-            FeatureKind::ConcatenateInGuards => Version::new(1, 15, 0),
+            FeatureKind::ConcatenateInGuards
+            | FeatureKind::IfExpression
+            | FeatureKind::LetElse
+            | FeatureKind::ConstantArithmetic
+            | FeatureKind::StringInterpolation
+            | FeatureKind::SubjectlessCase
+            | FeatureKind::PositionalFieldAccess
+            | FeatureKind::VariantWithInternalAnnotation
+            | FeatureKind::MustUseAnnotation
+            | FeatureKind::DeprecationSince => Version::new(1, 15, 0),
 
             FeatureKind::LabelShorthandSyntax | FeatureKind::ConstantStringConcatenation => {
                 Version::new(1, 4, 0)
@@ -1289,6 +1393,8 @@ impl Error {
             | Error::OutOfBoundsTupleIndex { location, .. }
             | Error::NotATuple { location, .. }
             | Error::NotATupleUnbound { location, .. }
+            | Error::OutOfBoundsPositionalField { location, .. }
+            | Error::PositionalAccessUnknownVariant { location, .. }
             | Error::RecordAccessUnknownType { location, .. }
             | Error::RecordUpdateInvalidConstructor { location, .. }
             | Error::UnexpectedTypeHole { location, .. }
@@ -1303,6 +1409,7 @@ impl Error {
             | Error::InvalidExternalJavascriptFunction { location, .. }
             | Error::InexhaustiveCaseExpression { location, .. }
             | Error::MissingCaseBody { location }
+            | Error::LetElseDoesNotDiverge { location }
             | Error::InexhaustiveLetAssignment { location, .. }
             | Error::UnusedTypeAliasParameter { location, .. }
             | Error::DuplicateTypeParameter { location, .. }
@@ -1319,12 +1426,15 @@ impl Error {
             | Error::EchoWithNoFollowingExpression { location }
             | Error::DeprecatedVariantOnDeprecatedType { location }
             | Error::LiteralFloatOutOfRange { location }
+            | Error::ConstantDivisionByZero { location }
+            | Error::InterpolatedValueNotString { location, .. }
             | Error::FloatOperatorOnInts { location, .. }
             | Error::IntOperatorOnFloats { location, .. }
             | Error::StringConcatenationWithAddInt { location }
             | Error::DoubleVariableAssignmentInBitArray { location }
             | Error::NonUtf8StringAssignmentInBitArray { location }
             | Error::PrivateOpaqueType { location }
+            | Error::InternalVariantFromOtherPackage { location, .. }
             | Error::SrcImportingDevDependency { location, .. }
             | Error::ExternalTypeWithConstructors { location, .. }
             | Error::LowercaseBoolPattern { location } => location.start,
@@ -1364,6 +1474,7 @@ impl Warning {
             | Warning::ImplicitlyDiscardedResult { location, .. }
             | Warning::UnusedLiteral { location, .. }
             | Warning::UnusedValue { location, .. }
+            | Warning::UnusedMustUseValue { location, .. }
             | Warning::NoFieldsRecordUpdate { location, .. }
             | Warning::AllFieldsRecordUpdate { location, .. }
             | Warning::UnusedType { location, .. }
@@ -1385,6 +1496,7 @@ impl Warning {
             | Warning::OpaqueExternalType { location, .. }
             | Warning::InternalTypeLeak { location, .. }
             | Warning::RedundantAssertAssignment { location, .. }
+            | Warning::RedundantLetElse { location, .. }
             | Warning::AssertAssignmentOnImpossiblePattern { location, .. }
             | Warning::TodoOrPanicUsedAsFunction { location, .. }
             | Warning::UnreachableCodeAfterPanic { location, .. }
@@ -1485,6 +1597,19 @@ pub enum UnsafeRecordUpdateReason {
         record_field_type: Arc<Type>,
         field: RecordField,
     },
+    /// The record is a constant defined in another module which refers to a
+    /// value that is private to that module.
+    PrivateValue {
+        module: EcoString,
+        name: EcoString,
+    },
+    /// The record is a constant defined in another module which refers to a
+    /// value from a module that isn't imported by the current module.
+    UnimportedModule {
+        constant_module: EcoString,
+        value_module: EcoString,
+        name: EcoString,
+    },
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -1773,6 +1898,21 @@ pub enum UnifyErrorSituation {
     FunctionsMismatch {
         reason: FunctionsMismatchReason,
     },
+
+    /// The condition of an `if` expression was not a `Bool`.
+    IfCondition,
+
+    /// The two branches of an `if` expression were found to return different
+    /// types.
+    IfBranchMismatch,
+
+    /// An `if` expression with no `else` branch was found to return something
+    /// other than `Nil`.
+    IfWithoutElse,
+
+    /// The left hand side of a clause of a `case` with no subjects was not a
+    /// `Bool`.
+    SubjectlessCaseCondition,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1819,6 +1959,22 @@ this list don't match the type of the elements being prepended to it.",
             ),
 
             Self::FunctionsMismatch { .. } => None,
+
+            Self::IfCondition => Some("The condition of an `if` expression must be a `Bool`."),
+
+            Self::IfBranchMismatch => Some(
+                "The `else` branch of this `if` was found to return a different type
+than the first branch, but both branches must return the same type.",
+            ),
+
+            Self::IfWithoutElse => Some(
+                "This `if` has no `else` branch, so its body must evaluate to `Nil`.
+Add an `else` branch if you want to use the value it produces.",
+            ),
+
+            Self::SubjectlessCaseCondition => {
+                Some("Each clause of a `case` with no subjects must be a `Bool` condition.")
+            }
         }
     }
 }
@@ -1867,6 +2023,22 @@ impl UnifyError {
         self.with_unify_error_situation(UnifyErrorSituation::CaseClauseMismatch { clause_location })
     }
 
+    pub fn if_condition_mismatch(self) -> Self {
+        self.with_unify_error_situation(UnifyErrorSituation::IfCondition)
+    }
+
+    pub fn if_branch_mismatch(self) -> Self {
+        self.with_unify_error_situation(UnifyErrorSituation::IfBranchMismatch)
+    }
+
+    pub fn if_without_else(self) -> Self {
+        self.with_unify_error_situation(UnifyErrorSituation::IfWithoutElse)
+    }
+
+    pub fn subjectless_case_condition_mismatch(self) -> Self {
+        self.with_unify_error_situation(UnifyErrorSituation::SubjectlessCaseCondition)
+    }
+
     pub fn list_element_mismatch(self) -> Self {
         self.with_unify_error_situation(UnifyErrorSituation::ListElementMismatch)
     }
@@ -5,12 +5,12 @@ use crate::{
     ast::{
         Arg, Assert, Assignment, AssignmentKind, BinOp, BitArrayOption, BitArraySegment,
         CAPTURE_VARIABLE, CallArg, Clause, ClauseGuard, Constant, FunctionLiteralKind, HasLocation,
-        ImplicitCallArgOrigin, InvalidExpression, Layer, RECORD_UPDATE_VARIABLE,
-        RecordBeingUpdated, SrcSpan, Statement, TodoKind, TypeAst, TypedArg, TypedAssert,
-        TypedAssignment, TypedClause, TypedClauseGuard, TypedConstant, TypedExpr,
-        TypedMultiPattern, TypedStatement, USE_ASSIGNMENT_VARIABLE, UntypedArg, UntypedAssert,
-        UntypedAssignment, UntypedClause, UntypedClauseGuard, UntypedConstant,
-        UntypedConstantBitArraySegment, UntypedExpr, UntypedExprBitArraySegment,
+        ImplicitCallArgOrigin, InterpolatedStringSegment, InvalidExpression, Layer, Pattern,
+        RECORD_UPDATE_VARIABLE, RecordBeingUpdated, SrcSpan, Statement, SubjectlessClause,
+        TodoKind, TypeAst, TypedArg, TypedAssert, TypedAssignment, TypedClause, TypedClauseGuard,
+        TypedConstant, TypedExpr, TypedMultiPattern, TypedStatement, USE_ASSIGNMENT_VARIABLE,
+        UntypedArg, UntypedAssert, UntypedAssignment, UntypedClause, UntypedClauseGuard,
+        UntypedConstant, UntypedConstantBitArraySegment, UntypedExpr, UntypedExprBitArraySegment,
         UntypedMultiPattern, UntypedStatement, UntypedUse, UntypedUseAssignment, Use,
         UseAssignment,
     },
@@ -466,6 +466,10 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                 location, value, ..
             } => Ok(self.infer_string(value, location)),
 
+            UntypedExpr::InterpolatedString { location, segments } => {
+                Ok(self.infer_interpolated_string(segments, location))
+            }
+
             UntypedExpr::PipeLine { expressions } => Ok(self.infer_pipeline(expressions)),
 
             UntypedExpr::Fn {
@@ -484,6 +488,17 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                 ..
             } => Ok(self.infer_case(subjects, clauses, location)),
 
+            UntypedExpr::If {
+                location,
+                condition,
+                then,
+                otherwise,
+            } => Ok(self.infer_if(location, *condition, *then, otherwise.map(|e| *e))),
+
+            UntypedExpr::SubjectlessCase { location, clauses } => {
+                Ok(self.infer_subjectless_case(location, clauses))
+            }
+
             UntypedExpr::List {
                 location,
                 elements,
@@ -653,6 +668,48 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
         }
     }
 
+    fn infer_interpolated_string(
+        &mut self,
+        segments: Vec<InterpolatedStringSegment>,
+        location: SrcSpan,
+    ) -> TypedExpr {
+        self.track_feature_usage(FeatureKind::StringInterpolation, location);
+
+        // The string is turned into a concatenation of its segments, so code
+        // generation never has to deal with interpolation.
+        segments
+            .into_iter()
+            .map(|segment| match segment {
+                InterpolatedStringSegment::Literal { location, value } => {
+                    self.infer_string(value, location)
+                }
+                InterpolatedStringSegment::Expression { expression, .. } => {
+                    let value = self.infer(expression);
+                    if unify(string(), value.type_()).is_ok() {
+                        return value;
+                    }
+                    self.problems.error(Error::InterpolatedValueNotString {
+                        location: value.location(),
+                        given: value.type_(),
+                    });
+                    TypedExpr::Invalid {
+                        location: value.location(),
+                        type_: string(),
+                        extra_information: None,
+                    }
+                }
+            })
+            .reduce(|left, right| TypedExpr::BinOp {
+                location: left.location().merge(&right.location()),
+                name: BinOp::Concatenate,
+                name_location: SrcSpan::new(right.location().start, right.location().start),
+                type_: string(),
+                left: Box::new(left),
+                right: Box::new(right),
+            })
+            .expect("Interpolated string with no segments")
+    }
+
     fn infer_int(&mut self, value: EcoString, int_value: BigInt, location: SrcSpan) -> TypedExpr {
         TypedExpr::Int {
             location,
@@ -679,11 +736,16 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
     /// Emit a warning if the given expressions should not be discarded.
     /// e.g. because it's a literal (why was it made in the first place?)
     /// e.g. because it's of the `Result` type (errors should be handled)
+    /// e.g. because it's returned by a function marked as `@must_use`
     fn expression_discarded(&mut self, discarded: &TypedExpr) {
         if discarded.is_literal() {
             self.problems.warning(Warning::UnusedLiteral {
                 location: discarded.location(),
             });
+        } else if discarded.is_must_use_call() {
+            self.problems.warning(Warning::UnusedMustUseValue {
+                location: discarded.location(),
+            });
         } else if discarded.type_().is_result() {
             self.problems.warning(Warning::ImplicitlyDiscardedResult {
                 location: discarded.location(),
@@ -765,8 +827,13 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                     statements.push(Statement::Expression(expression));
                 }
                 Statement::Assignment(assignment) => {
-                    let assignment = self.infer_assignment(*assignment);
-                    statements.push(Statement::Assignment(Box::new(assignment)));
+                    if let AssignmentKind::LetElse { .. } = assignment.kind {
+                        let statement = self.infer_let_else(assignment, untyped.collect());
+                        statements.push(statement);
+                        break; // Inferring the let else has consumed the rest of the exprs
+                    }
+                    let assignment = Box::new(self.infer_assignment(*assignment));
+                    statements.push(Statement::Assignment(assignment));
                 }
                 Statement::Assert(assert) => {
                     let assert = self.infer_assert(assert);
@@ -1537,13 +1604,94 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                 location: tuple.location(),
             }),
 
-            Type::Named { .. } | Type::Fn { .. } | Type::Var { .. } => Err(Error::NotATuple {
+            Type::Named { .. } => self.infer_positional_access(tuple, index, location),
+
+            Type::Fn { .. } | Type::Var { .. } => Err(Error::NotATuple {
                 location: tuple.location(),
                 given: tuple.type_(),
             }),
         }
     }
 
+    /// Infers an access like `cat.0` to the unlabelled fields of a custom type
+    /// record. The variant of the record must be known, either because the
+    /// type has a single variant or because it has been inferred.
+    fn infer_positional_access(
+        &mut self,
+        record: TypedExpr,
+        index: u64,
+        location: SrcSpan,
+    ) -> Result<TypedExpr, Error> {
+        let record_type = record.type_();
+        let not_a_record = || Error::NotATuple {
+            location: record.location(),
+            given: record_type.clone(),
+        };
+
+        let (accessors_map, inferred_variant) = match collapse_links(record_type.clone()).as_ref() {
+            Type::Named {
+                module,
+                name,
+                inferred_variant,
+                ..
+            } => {
+                let accessors_map = if module == &self.environment.current_module {
+                    self.environment.accessors.get(name)
+                } else {
+                    self.environment
+                        .importable_modules
+                        .get(module)
+                        .and_then(|module| module.accessors.get(name))
+                        .filter(|accessors_map| accessors_map.publicity.is_importable())
+                };
+                (accessors_map.ok_or_else(not_a_record)?, *inferred_variant)
+            }
+
+            Type::Fn { .. } | Type::Var { .. } | Type::Tuple { .. } => return Err(not_a_record()),
+        };
+
+        let variant = match inferred_variant {
+            Some(variant) => variant,
+            None if accessors_map.variant_positional_accessors.len() == 1 => 0,
+            None => {
+                return Err(Error::PositionalAccessUnknownVariant {
+                    location: record.location(),
+                    type_: record_type.clone(),
+                });
+            }
+        };
+        let fields = accessors_map
+            .positional_accessors(variant)
+            .ok_or_else(not_a_record)?;
+        let field_type = fields
+            .get(index as usize)
+            .ok_or_else(|| Error::OutOfBoundsPositionalField {
+                location: SrcSpan {
+                    start: record.location().end,
+                    end: location.end,
+                },
+                index,
+                size: fields.len(),
+            })?
+            .clone();
+        let accessor_record_type = accessors_map.type_.clone();
+
+        self.track_feature_usage(FeatureKind::PositionalFieldAccess, location);
+
+        let mut type_vars = hashmap![];
+        let accessor_record_type = self.instantiate(accessor_record_type, &mut type_vars);
+        let type_ = self.instantiate(field_type, &mut type_vars);
+        unify(accessor_record_type, record_type)
+            .map_err(|e| convert_unify_error(e, record.location()))?;
+
+        Ok(TypedExpr::PositionalAccess {
+            location,
+            type_,
+            index,
+            record: Box::new(record),
+        })
+    }
+
     fn infer_bit_array(
         &mut self,
         segments: Vec<UntypedExprBitArraySegment>,
@@ -1558,7 +1706,8 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                 // implicitly given the float type option.
                 if !segment.has_type_option() {
                     match segment.value.as_ref() {
-                        UntypedExpr::String { location, .. } => {
+                        UntypedExpr::String { location, .. }
+                        | UntypedExpr::InterpolatedString { location, .. } => {
                             self.track_feature_usage(
                                 FeatureKind::UnannotatedUtf8StringSegment,
                                 *location,
@@ -1587,6 +1736,8 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                         | UntypedExpr::BinOp { .. }
                         | UntypedExpr::PipeLine { .. }
                         | UntypedExpr::Case { .. }
+                        | UntypedExpr::If { .. }
+                        | UntypedExpr::SubjectlessCase { .. }
                         | UntypedExpr::FieldAccess { .. }
                         | UntypedExpr::Tuple { .. }
                         | UntypedExpr::TupleIndex { .. }
@@ -1667,6 +1818,7 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                         | Constant::BitArray { .. }
                         | Constant::Var { .. }
                         | Constant::StringConcatenation { .. }
+                        | Constant::BinOp { .. }
                         | Constant::Invalid { .. } => (),
                     }
                 }
@@ -2144,11 +2296,17 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
 
         match (&kind, not_exhaustive_error) {
             // The pattern is exhaustive in a let assignment, there's no problem here.
-            (AssignmentKind::Let | AssignmentKind::Generated, Ok(_)) => (),
+            (
+                AssignmentKind::Let | AssignmentKind::Generated | AssignmentKind::LetElse { .. },
+                Ok(_),
+            ) => (),
 
             // If the pattern is not exhaustive and we're not asserting we want to
             // report the error!
-            (AssignmentKind::Let | AssignmentKind::Generated, Err(e)) => {
+            (
+                AssignmentKind::Let | AssignmentKind::Generated | AssignmentKind::LetElse { .. },
+                Err(e),
+            ) => {
                 self.problems.error(e);
             }
 
@@ -2202,6 +2360,167 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
         }
     }
 
+    /// A `let else` assignment is desugared into a case expression, where the
+    /// first clause wraps all the statements that follow the assignment:
+    ///
+    /// ```gleam
+    /// let Ok(a) = result else { panic }
+    /// a + 1
+    /// // becomes
+    /// case result {
+    ///   Ok(a) -> { a + 1 }
+    ///   _ -> { panic }
+    /// }
+    /// ```
+    ///
+    /// If nothing follows the assignment the first clause evaluates to `Nil`.
+    ///
+    fn infer_let_else(
+        &mut self,
+        assignment: Box<UntypedAssignment>,
+        following: Vec<UntypedStatement>,
+    ) -> TypedStatement {
+        let Assignment {
+            location,
+            value,
+            pattern,
+            annotation,
+            kind:
+                AssignmentKind::LetElse {
+                    else_location,
+                    body,
+                },
+            compiled_case: _,
+        } = *assignment
+        else {
+            panic!("infer_let_else called on an assignment that isn't a let else")
+        };
+        let body = *body;
+
+        self.track_feature_usage(FeatureKind::LetElse, else_location);
+
+        self.previous_panics = false;
+        let value = self.expr_in_new_scope(|this| this.infer(value));
+        let value_panics = self.previous_panics;
+
+        // Check that any type annotation is accurate.
+        if let Some(annotation) = &annotation {
+            match self
+                .type_from_ast(annotation)
+                .map(|type_| self.instantiate(type_, &mut hashmap![]))
+            {
+                Ok(annotated_type) => {
+                    if let Err(error) = unify(annotated_type, value.type_())
+                        .map_err(|e| convert_unify_error(e, value.type_defining_location()))
+                    {
+                        self.problems.error(error);
+                    }
+                }
+                Err(error) => self.problems.error(error),
+            }
+        }
+
+        let subjects = vec![value];
+        let pattern_location = pattern.location();
+        let else_clause_location = SrcSpan::new(else_location.start, location.end);
+
+        // The else body is checked first as it comes first in the source, and
+        // it can't use any of the variables bound by the pattern.
+        let body_location = body.location();
+        let else_clause = Clause {
+            location: else_clause_location,
+            pattern: vec![Pattern::Discard {
+                name: "_".into(),
+                location: else_location,
+                type_: (),
+            }],
+            alternative_patterns: vec![],
+            guard: None,
+            then: body,
+        };
+        self.previous_panics = false;
+        let (else_clause, else_errored) = self.infer_clause(else_clause, &subjects);
+        // A `todo` doesn't count as a panic for the purpose of unreachable
+        // code warnings, but it's a perfectly fine way to end an else body.
+        let else_diverges = self.previous_panics || else_clause.then.ends_with_todo();
+        if !else_diverges {
+            self.problems.error(Error::LetElseDoesNotDiverge {
+                location: body_location,
+            });
+        }
+
+        let then = Vec1::try_from_vec(following)
+            .ok()
+            .map(|statements| UntypedExpr::Block {
+                location: SrcSpan::new(
+                    statements.first().location().start,
+                    statements.last().location().end,
+                ),
+                statements,
+            });
+        let then_end = then
+            .as_ref()
+            .map(|then| then.location().end)
+            .unwrap_or(location.end);
+        let case_location = SrcSpan::new(location.start, then_end);
+        let match_clause = Clause {
+            location: SrcSpan::new(pattern_location.start, then_end),
+            pattern: vec![pattern],
+            alternative_patterns: vec![],
+            guard: None,
+            then,
+        };
+        self.previous_panics = false;
+        let (match_clause, match_errored) =
+            self.infer_clause_with(match_clause, &subjects, |this, then| match then {
+                Some(then) => this.infer(then),
+                None => nil_value(SrcSpan::new(location.end, location.end)),
+            });
+        self.previous_panics = value_panics || self.previous_panics;
+
+        let type_ = match_clause.then.type_();
+        if else_diverges && let Err(error) = unify(type_.clone(), else_clause.then.type_()) {
+            self.problems.error(
+                error
+                    .case_clause_mismatch(else_clause.location)
+                    .into_error(else_clause.then.type_defining_location()),
+            );
+        }
+
+        let clauses = vec![match_clause, else_clause];
+        let compiled_case = if match_errored || else_errored {
+            CompiledCase::failure()
+        } else {
+            let subject_types = subjects.iter().map(|subject| subject.type_()).collect_vec();
+            let mut case = exhaustiveness::CaseToCompile::new(&subject_types);
+            clauses.iter().for_each(|clause| case.add_clause(clause));
+            let result = case.compile(self.environment);
+
+            if let Reachability::Unreachable(reason) = result.is_reachable(0, 0) {
+                self.problems.warning(Warning::UnreachableCasePattern {
+                    location: pattern_location,
+                    reason,
+                });
+            }
+            // If the pattern already covers all possible values there's no
+            // need for an else body.
+            if let Reachability::Unreachable(_) = result.is_reachable(1, 0) {
+                self.problems.warning(Warning::RedundantLetElse {
+                    location: else_clause_location,
+                });
+            }
+            result.compiled_case
+        };
+
+        Statement::Expression(TypedExpr::Case {
+            location: case_location,
+            type_,
+            compiled_case,
+            subjects,
+            clauses,
+        })
+    }
+
     fn infer_assignment_kind(
         &mut self,
         kind: AssignmentKind<UntypedExpr>,
@@ -2209,6 +2528,9 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
         match kind {
             AssignmentKind::Let => AssignmentKind::Let,
             AssignmentKind::Generated => AssignmentKind::Generated,
+            AssignmentKind::LetElse { .. } => {
+                panic!("let else assignments are desugared before being inferred")
+            }
             AssignmentKind::Assert {
                 location,
                 message,
@@ -2371,6 +2693,259 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
         }
     }
 
+    /// An `if` expression is desugared into a case expression matching on the
+    /// condition:
+    ///
+    /// ```gleam
+    /// if condition { a } else { b }
+    /// // becomes
+    /// case condition {
+    ///   True -> { a }
+    ///   False -> { b }
+    /// }
+    /// ```
+    ///
+    /// When there's no `else` branch the `False` clause evaluates to `Nil`, so
+    /// the `if` body must be `Nil` as well.
+    ///
+    fn infer_if(
+        &mut self,
+        location: SrcSpan,
+        condition: UntypedExpr,
+        then: UntypedExpr,
+        otherwise: Option<UntypedExpr>,
+    ) -> TypedExpr {
+        self.track_feature_usage(FeatureKind::IfExpression, location);
+
+        let condition_location = condition.location();
+        self.previous_panics = false;
+        let condition = self.expr_in_new_scope(|this| this.infer(condition));
+        let condition_panics = self.previous_panics;
+
+        if let Err(error) = unify(bool(), condition.type_()) {
+            self.problems.error(
+                error
+                    .if_condition_mismatch()
+                    .into_error(condition.type_defining_location()),
+            );
+        }
+
+        self.previous_panics = false;
+        let then_clause = self.infer_bool_clause(true, condition_location, |this| this.infer(then));
+        let then_panics = self.previous_panics;
+
+        let has_else = otherwise.is_some();
+        self.previous_panics = false;
+        let else_clause =
+            self.infer_bool_clause(false, condition_location, |this| match otherwise {
+                Some(otherwise) => this.infer(otherwise),
+                None => nil_value(SrcSpan::new(location.end, location.end)),
+            });
+        let else_panics = self.previous_panics;
+
+        self.previous_panics = condition_panics || (then_panics && else_panics);
+
+        let type_ = then_clause.then.type_();
+        if has_else {
+            if let Err(error) = unify(type_.clone(), else_clause.then.type_()) {
+                self.problems.error(
+                    error
+                        .if_branch_mismatch()
+                        .into_error(else_clause.then.type_defining_location()),
+                );
+            }
+        } else if let Err(error) = unify(nil(), type_.clone()) {
+            self.problems.error(
+                error
+                    .if_without_else()
+                    .into_error(then_clause.then.type_defining_location()),
+            );
+        }
+
+        let clauses = vec![then_clause, else_clause];
+        let compiled_case = self.check_case_exhaustiveness(location, &[bool()], &clauses);
+
+        TypedExpr::Case {
+            location,
+            type_,
+            compiled_case,
+            subjects: vec![condition],
+            clauses,
+        }
+    }
+
+    fn infer_subjectless_case(
+        &mut self,
+        location: SrcSpan,
+        clauses: Vec<SubjectlessClause>,
+    ) -> TypedExpr {
+        self.track_feature_usage(FeatureKind::SubjectlessCase, location);
+
+        // The first catch-all clause is the final fallback, any clause after it
+        // is checked separately as it can never be reached.
+        let mut clauses = clauses.into_iter();
+        let mut conditional_clauses = vec![];
+        let mut catch_all = None;
+        for clause in clauses.by_ref() {
+            match clause.condition {
+                Some(condition) => {
+                    conditional_clauses.push((clause.location, condition, clause.then))
+                }
+                None => {
+                    catch_all = Some((clause.location, clause.then));
+                    break;
+                }
+            }
+        }
+
+        let mut type_ = None;
+        let mut first_condition_panics = false;
+        let mut all_clauses_panic = true;
+        let mut branches = Vec::with_capacity(conditional_clauses.len());
+        for (index, (clause_location, condition, then)) in
+            conditional_clauses.into_iter().enumerate()
+        {
+            let condition_location = condition.location();
+            self.previous_panics = false;
+            let condition = self.expr_in_new_scope(|this| this.infer(condition));
+            let condition_panics = self.previous_panics;
+
+            if let Err(error) = unify(bool(), condition.type_()) {
+                self.problems.error(
+                    error
+                        .subjectless_case_condition_mismatch()
+                        .into_error(condition.type_defining_location()),
+                );
+            }
+
+            self.previous_panics = false;
+            let then_clause =
+                self.infer_bool_clause(true, condition_location, |this| this.infer(then));
+            all_clauses_panic = all_clauses_panic && self.previous_panics;
+            self.unify_subjectless_case_clause(&mut type_, &then_clause.then, clause_location);
+
+            // Only the first condition is always evaluated.
+            if index == 0 {
+                first_condition_panics = condition_panics;
+            }
+
+            branches.push((clause_location, condition, then_clause));
+        }
+
+        let otherwise = match catch_all {
+            Some((clause_location, then)) => {
+                self.previous_panics = false;
+                let otherwise = self.infer(then);
+                all_clauses_panic = all_clauses_panic && self.previous_panics;
+                self.unify_subjectless_case_clause(&mut type_, &otherwise, clause_location);
+                otherwise
+            }
+            None => {
+                self.problems.error(Error::InexhaustiveCaseExpression {
+                    location,
+                    missing: vec!["_".into()],
+                });
+                TypedExpr::Invalid {
+                    location: SrcSpan::new(location.end, location.end),
+                    type_: type_.clone().unwrap_or_else(|| self.new_unbound_var()),
+                    extra_information: None,
+                }
+            }
+        };
+
+        for unreachable in clauses {
+            let location = match &unreachable.condition {
+                Some(condition) => condition.location(),
+                None => SrcSpan::new(unreachable.location.start, unreachable.location.start + 1),
+            };
+            self.problems.warning(Warning::UnreachableCasePattern {
+                location,
+                reason: UnreachablePatternReason::DuplicatePattern,
+            });
+            if let Some(condition) = unreachable.condition {
+                let _ = self.expr_in_new_scope(|this| this.infer(condition));
+            }
+            let _ = self.expr_in_new_scope(|this| this.infer(unreachable.then));
+        }
+        self.previous_panics = first_condition_panics || all_clauses_panic;
+
+        // Each conditional clause becomes a `case` on its condition, with the
+        // remaining clauses in its `False` branch.
+        let mut expression = otherwise;
+        for (index, (clause_location, condition, then_clause)) in
+            branches.into_iter().enumerate().rev()
+        {
+            let else_clause = Clause {
+                location: expression.location(),
+                pattern: vec![bool_pattern(false, condition.location())],
+                alternative_patterns: vec![],
+                guard: None,
+                then: expression,
+            };
+
+            // The outermost `case` covers the whole expression, including the
+            // `case` keyword.
+            let location = if index == 0 {
+                location
+            } else {
+                SrcSpan::new(clause_location.start, location.end)
+            };
+            let case_type = then_clause.then.type_();
+            let clauses = vec![then_clause, else_clause];
+            let compiled_case = self.check_case_exhaustiveness(location, &[bool()], &clauses);
+
+            expression = TypedExpr::Case {
+                location,
+                type_: case_type,
+                compiled_case,
+                subjects: vec![condition],
+                clauses,
+            };
+        }
+
+        expression
+    }
+
+    /// Unifies the type returned by a clause of a `case` with no subjects
+    /// with the one returned by the clauses before it.
+    fn unify_subjectless_case_clause(
+        &mut self,
+        type_: &mut Option<Arc<Type>>,
+        then: &TypedExpr,
+        clause_location: SrcSpan,
+    ) {
+        match type_ {
+            None => *type_ = Some(then.type_()),
+            Some(expected) => {
+                if let Err(error) = unify(expected.clone(), then.type_()) {
+                    self.problems.error(
+                        error
+                            .case_clause_mismatch(clause_location)
+                            .into_error(then.type_defining_location()),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Infers a clause matching on the `True` or `False` constructor, used to
+    /// turn conditional expressions into a `case` on a `Bool`.
+    fn infer_bool_clause(
+        &mut self,
+        value: bool,
+        pattern_location: SrcSpan,
+        infer_then: impl FnOnce(&mut Self) -> TypedExpr,
+    ) -> TypedClause {
+        let then = self.value_in_new_scope(infer_then);
+        Clause {
+            location: then.location(),
+            pattern: vec![bool_pattern(value, pattern_location)],
+            alternative_patterns: vec![],
+            guard: None,
+            then,
+        }
+    }
+
     /// Returns a tuple with the typed clause and a bool that is true if an error
     /// was encountered while typing the clause patterns.
     ///
@@ -2378,6 +2953,17 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
         &mut self,
         clause: UntypedClause,
         subjects: &[TypedExpr],
+    ) -> (TypedClause, bool) {
+        self.infer_clause_with(clause, subjects, |this, then| this.infer(then))
+    }
+
+    /// Infers a clause whose body is typed by `infer_then`, for clauses built
+    /// by the compiler whose body may not come from the source.
+    fn infer_clause_with<Then>(
+        &mut self,
+        clause: Clause<Then, (), ()>,
+        subjects: &[TypedExpr],
+        infer_then: impl FnOnce(&mut Self, Then) -> TypedExpr,
     ) -> (TypedClause, bool) {
         let Clause {
             pattern,
@@ -2398,7 +2984,7 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                     None
                 }
             };
-            let then = this.infer(then);
+            let then = infer_then(this, then);
             let clause = Clause {
                 location,
                 pattern: typed_pattern,
@@ -2791,10 +3377,11 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                     })?;
 
             // Emit a warning if the value being used is deprecated.
-            if let Deprecation::Deprecated { message } = &constructor.deprecation {
+            if let Deprecation::Deprecated { message, since } = &constructor.deprecation {
                 self.problems.warning(Warning::DeprecatedItem {
                     location: select_location,
                     message: message.clone(),
+                    since: since.clone(),
                     layer: Layer::Value,
                 })
             }
@@ -2806,6 +3393,12 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
             (module.name.clone(), constructor.clone())
         };
 
+        self.check_internal_variant_usage(
+            &constructor.variant,
+            &constructor.type_,
+            select_location,
+        );
+
         let type_ = self.instantiate(constructor.type_, &mut hashmap![]);
 
         self.narrow_implementations(select_location, &constructor.variant)?;
@@ -3467,6 +4060,308 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
         }
     }
 
+    /// Returns the name a module is imported as in the current module, if it
+    /// has been imported.
+    fn imported_module_alias(&self, module: &EcoString) -> Option<&EcoString> {
+        self.environment
+            .imported_modules
+            .iter()
+            .find(|(_, (_, interface))| &interface.name == module)
+            .map(|(alias, _)| alias)
+    }
+
+    /// Returns the value of a constant, following references to other
+    /// constants. The value of a constant defined in another module is
+    /// rewritten so that it can be used in the current module, see
+    /// `qualify_imported_constant`.
+    ///
+    fn constant_value(
+        &mut self,
+        constant: TypedConstant,
+        location: SrcSpan,
+    ) -> Result<TypedConstant, Error> {
+        let mut constant = constant;
+        while let Constant::Var {
+            constructor: Some(constructor),
+            ..
+        } = &constant
+            && let ValueConstructorVariant::ModuleConstant {
+                literal, module, ..
+            } = &constructor.variant
+        {
+            constant = if *module == self.environment.current_module {
+                literal.clone()
+            } else {
+                self.qualify_imported_constant(literal.clone(), module, location)?
+            };
+        }
+        Ok(constant)
+    }
+
+    /// Rewrites a constant defined in `module` so that it can be used as part
+    /// of a constant in the current module: references to other constants are
+    /// replaced by their values, and references to functions and records are
+    /// qualified with the alias their module is imported with here.
+    ///
+    /// If the constant refers to a value that can't be referenced from the
+    /// current module an error is returned, pointing at `location`.
+    ///
+    fn qualify_imported_constant(
+        &mut self,
+        constant: TypedConstant,
+        module: &EcoString,
+        location: SrcSpan,
+    ) -> Result<TypedConstant, Error> {
+        let constant = match constant {
+            Constant::Var {
+                location: var_location,
+                module: var_module,
+                name,
+                type_,
+                constructor: Some(constructor),
+            } => match &constructor.variant {
+                ValueConstructorVariant::ModuleConstant { literal, .. } => {
+                    self.qualify_imported_constant(literal.clone(), module, location)?
+                }
+
+                // The value could have been imported under a different name
+                // in `module`, so the name it is defined with is used.
+                ValueConstructorVariant::ModuleFn {
+                    module: value_module,
+                    name: value_name,
+                    ..
+                }
+                | ValueConstructorVariant::Record {
+                    module: value_module,
+                    name: value_name,
+                    ..
+                } => {
+                    let name = value_name.clone();
+                    let alias = self.imported_value_alias(
+                        module,
+                        value_module,
+                        &name,
+                        constructor.publicity,
+                        location,
+                    )?;
+                    Constant::Var {
+                        location: var_location,
+                        module: alias.map(|alias| (alias, var_location)),
+                        name,
+                        type_,
+                        constructor: Some(constructor),
+                    }
+                }
+
+                ValueConstructorVariant::LocalVariable { .. } => Constant::Var {
+                    location: var_location,
+                    module: var_module,
+                    name,
+                    type_,
+                    constructor: Some(constructor),
+                },
+            },
+
+            Constant::Record {
+                location: record_location,
+                module: record_module,
+                name,
+                arguments,
+                tag,
+                type_,
+                field_map,
+                record_constructor,
+            } => {
+                let (record_module, name) = match record_constructor.as_deref() {
+                    Some(ValueConstructor {
+                        publicity,
+                        variant:
+                            ValueConstructorVariant::Record {
+                                module: value_module,
+                                name: value_name,
+                                ..
+                            },
+                        ..
+                    }) => {
+                        let alias = self.imported_value_alias(
+                            module,
+                            value_module,
+                            value_name,
+                            *publicity,
+                            location,
+                        )?;
+                        (
+                            alias.map(|alias| (alias, record_location)),
+                            value_name.clone(),
+                        )
+                    }
+                    Some(_) | None => (record_module, name),
+                };
+
+                let arguments = arguments
+                    .into_iter()
+                    .map(|argument| {
+                        let value =
+                            self.qualify_imported_constant(argument.value, module, location)?;
+                        Ok(CallArg { value, ..argument })
+                    })
+                    .collect::<Result<_, Error>>()?;
+
+                Constant::Record {
+                    location: record_location,
+                    module: record_module,
+                    name,
+                    arguments,
+                    tag,
+                    type_,
+                    field_map,
+                    record_constructor,
+                }
+            }
+
+            Constant::Tuple {
+                location: tuple_location,
+                elements,
+                type_,
+            } => Constant::Tuple {
+                location: tuple_location,
+                elements: elements
+                    .into_iter()
+                    .map(|element| self.qualify_imported_constant(element, module, location))
+                    .collect::<Result<_, _>>()?,
+                type_,
+            },
+
+            Constant::List {
+                location: list_location,
+                elements,
+                type_,
+            } => Constant::List {
+                location: list_location,
+                elements: elements
+                    .into_iter()
+                    .map(|element| self.qualify_imported_constant(element, module, location))
+                    .collect::<Result<_, _>>()?,
+                type_,
+            },
+
+            Constant::BitArray {
+                location: bit_array_location,
+                segments,
+            } => Constant::BitArray {
+                location: bit_array_location,
+                segments: segments
+                    .into_iter()
+                    .map(|segment| {
+                        let value =
+                            self.qualify_imported_constant(*segment.value, module, location)?;
+                        Ok(BitArraySegment {
+                            value: Box::new(value),
+                            ..segment
+                        })
+                    })
+                    .collect::<Result<_, Error>>()?,
+            },
+
+            Constant::StringConcatenation {
+                location: concatenation_location,
+                left,
+                right,
+            } => Constant::StringConcatenation {
+                location: concatenation_location,
+                left: Box::new(self.qualify_imported_constant(*left, module, location)?),
+                right: Box::new(self.qualify_imported_constant(*right, module, location)?),
+            },
+
+            // Record updates and arithmetic are evaluated during type checking,
+            // so they never appear in the value of a constant.
+            Constant::RecordUpdate { .. } | Constant::BinOp { .. } => {
+                panic!("record updates and arithmetic should have been evaluated")
+            }
+
+            Constant::Int { .. }
+            | Constant::Float { .. }
+            | Constant::String { .. }
+            | Constant::Var { .. }
+            | Constant::Invalid { .. } => constant,
+        };
+        Ok(constant)
+    }
+
+    /// Returns the alias to use in the current module to refer to the value
+    /// `name` defined in `value_module`, as part of a constant copied from
+    /// `constant_module`. Prelude values don't need to be qualified.
+    ///
+    fn imported_value_alias(
+        &mut self,
+        constant_module: &EcoString,
+        value_module: &EcoString,
+        name: &EcoString,
+        publicity: Publicity,
+        location: SrcSpan,
+    ) -> Result<Option<EcoString>, Error> {
+        if value_module == PRELUDE_MODULE_NAME {
+            return Ok(None);
+        }
+
+        if publicity.is_private() {
+            return Err(Error::UnsafeRecordUpdate {
+                location,
+                reason: UnsafeRecordUpdateReason::PrivateValue {
+                    module: value_module.clone(),
+                    name: name.clone(),
+                },
+            });
+        }
+
+        let Some(alias) = self.imported_module_alias(value_module).cloned() else {
+            return Err(Error::UnsafeRecordUpdate {
+                location,
+                reason: UnsafeRecordUpdateReason::UnimportedModule {
+                    constant_module: constant_module.clone(),
+                    value_module: value_module.clone(),
+                    name: name.clone(),
+                },
+            });
+        };
+
+        self.environment
+            .references
+            .register_module_reference(alias.clone());
+        Ok(Some(alias))
+    }
+
+    /// Reports an error if the given value is a variant marked as `@internal`
+    /// that belongs to a different package.
+    ///
+    fn check_internal_variant_usage(
+        &mut self,
+        variant: &ValueConstructorVariant,
+        type_: &Type,
+        location: SrcSpan,
+    ) {
+        let ValueConstructorVariant::Record {
+            name,
+            variant_index,
+            ..
+        } = variant
+        else {
+            return;
+        };
+
+        if let Some(package) = self
+            .environment
+            .internal_variant_package(type_, *variant_index)
+            .cloned()
+        {
+            self.problems.error(Error::InternalVariantFromOtherPackage {
+                location,
+                name: name.clone(),
+                package,
+            });
+        }
+    }
+
     fn infer_value_constructor(
         &mut self,
         module: &Option<(EcoString, SrcSpan)>,
@@ -3534,14 +4429,17 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
         self.check_recursive_argument_usage(name, &variant, &register_reference);
 
         // Emit a warning if the value being used is deprecated.
-        if let Deprecation::Deprecated { message } = &deprecation {
+        if let Deprecation::Deprecated { message, since } = &deprecation {
             self.problems.warning(Warning::DeprecatedItem {
                 location: *location,
                 message: message.clone(),
+                since: since.clone(),
                 layer: Layer::Value,
             })
         }
 
+        self.check_internal_variant_usage(&variant, &type_, *location);
+
         self.narrow_implementations(*location, &variant)?;
 
         match register_reference {
@@ -3809,13 +4707,22 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                 };
 
                 // If the record being updated is a reference to a constant variable, resolve
-                // it to get the actual record value
+                // it to get the actual record value. Values of constants defined in another
+                // module are rewritten so that they can be referenced from this module.
                 let resolved_record = match &typed_record {
                     Constant::Var {
                         constructor: Some(value_constructor),
                         ..
                     } => match &value_constructor.variant {
-                        ValueConstructorVariant::ModuleConstant { literal, .. } => literal.clone(),
+                        ValueConstructorVariant::ModuleConstant { .. } => {
+                            match self.constant_value(typed_record, record.location) {
+                                Ok(value) => value,
+                                Err(error) => {
+                                    self.problems.error(error);
+                                    return self.new_invalid_constant(location);
+                                }
+                            }
+                        }
                         ValueConstructorVariant::LocalVariable { .. }
                         | ValueConstructorVariant::ModuleFn { .. }
                         | ValueConstructorVariant::Record { .. } => typed_record,
@@ -3830,6 +4737,7 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                     | Constant::BitArray { .. }
                     | Constant::Var { .. }
                     | Constant::StringConcatenation { .. }
+                    | Constant::BinOp { .. }
                     | Constant::Invalid { .. } => typed_record,
                 };
 
@@ -3862,6 +4770,21 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                     return self.new_invalid_constant(location);
                 }
 
+                // Check that the record being spread belongs to the constructor's type.
+                // Variants of different types can share a name, for example when the
+                // constructor and the record come from different modules.
+                if expected_type.named_type_name() != typed_record_type.named_type_name() {
+                    self.problems.error(convert_unify_error(
+                        UnifyError::CouldNotUnify {
+                            expected: expected_type.clone(),
+                            given: typed_record_type,
+                            situation: None,
+                        },
+                        record.location,
+                    ));
+                    return self.new_invalid_constant(location);
+                }
+
                 // Emit warning if no fields are being overridden
                 if arguments.is_empty() {
                     self.problems
@@ -4283,6 +5206,17 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
                 }
             }
 
+            Constant::BinOp {
+                location,
+                name,
+                left,
+                right,
+                ..
+            } => {
+                self.track_feature_usage(FeatureKind::ConstantArithmetic, location);
+                self.infer_const_binop(location, name, *left, *right)
+            }
+
             Constant::Invalid { .. } => panic!("invalid constants can not be in an untyped ast"),
         }
     }
@@ -4329,6 +5263,115 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
         }
     }
 
+    /// Arithmetic in constants is evaluated at compile time, so rather than a
+    /// binary operation this returns the literal Int or Float it results in.
+    ///
+    fn infer_const_binop(
+        &mut self,
+        location: SrcSpan,
+        name: BinOp,
+        left: UntypedConstant,
+        right: UntypedConstant,
+    ) -> TypedConstant {
+        let operand_type = if name.is_float_operator() {
+            float()
+        } else {
+            int()
+        };
+
+        let left = self.infer_const(&None, left);
+        if let Err(error) = unify(operand_type.clone(), left.type_()) {
+            self.problems
+                .error(error.operator_situation(name).into_error(left.location()));
+        }
+
+        let right = self.infer_const(&None, right);
+        if let Err(error) = unify(operand_type.clone(), right.type_()) {
+            self.problems
+                .error(error.operator_situation(name).into_error(right.location()));
+        }
+
+        let invalid = Constant::Invalid {
+            location,
+            type_: operand_type,
+            extra_information: None,
+        };
+
+        match (constant_literal(&left), constant_literal(&right)) {
+            (
+                Constant::Int {
+                    int_value: left, ..
+                },
+                Constant::Int {
+                    int_value: right, ..
+                },
+            ) if !name.is_float_operator() => {
+                let int_value = match name {
+                    BinOp::AddInt => left + right,
+                    BinOp::SubInt => left - right,
+                    BinOp::MultInt => left * right,
+                    BinOp::DivInt | BinOp::RemainderInt if *right == BigInt::ZERO => {
+                        self.problems
+                            .error(Error::ConstantDivisionByZero { location });
+                        return invalid;
+                    }
+                    BinOp::DivInt => left / right,
+                    BinOp::RemainderInt => left % right,
+                    _ => return invalid,
+                };
+
+                if self.environment.target == Target::JavaScript {
+                    check_javascript_int_safety(&int_value, location, self.problems);
+                }
+
+                Constant::Int {
+                    location,
+                    value: int_value.to_string().into(),
+                    int_value,
+                }
+            }
+
+            (
+                Constant::Float {
+                    float_value: left, ..
+                },
+                Constant::Float {
+                    float_value: right, ..
+                },
+            ) if name.is_float_operator() => {
+                let (left, right) = (left.value(), right.value());
+                let value = match name {
+                    BinOp::AddFloat => left + right,
+                    BinOp::SubFloat => left - right,
+                    BinOp::MultFloat => left * right,
+                    BinOp::DivFloat if right == 0.0 => {
+                        self.problems
+                            .error(Error::ConstantDivisionByZero { location });
+                        return invalid;
+                    }
+                    BinOp::DivFloat => left / right,
+                    _ => return invalid,
+                };
+
+                let value = float_literal(value);
+                let Some(float_value) = LiteralFloatValue::parse(&value) else {
+                    return invalid;
+                };
+                check_float_safety(float_value, location, self.problems);
+
+                Constant::Float {
+                    location,
+                    value,
+                    float_value,
+                }
+            }
+
+            // If either side couldn't be typed there's nothing to evaluate,
+            // an error has already been reported.
+            _ => invalid,
+        }
+    }
+
     fn infer_const_tuple(
         &mut self,
         untyped_elements: Vec<UntypedConstant>,
@@ -4458,6 +5501,7 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
             UntypedExpr::Int { .. }
             | UntypedExpr::Float { .. }
             | UntypedExpr::String { .. }
+            | UntypedExpr::InterpolatedString { .. }
             | UntypedExpr::Block { .. }
             | UntypedExpr::Var { .. }
             | UntypedExpr::Fn { .. }
@@ -4466,6 +5510,8 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
             | UntypedExpr::BinOp { .. }
             | UntypedExpr::PipeLine { .. }
             | UntypedExpr::Case { .. }
+            | UntypedExpr::If { .. }
+            | UntypedExpr::SubjectlessCase { .. }
             | UntypedExpr::Tuple { .. }
             | UntypedExpr::TupleIndex { .. }
             | UntypedExpr::Todo { .. }
@@ -5202,11 +6248,56 @@ impl<'a, 'b> ExprTyper<'a, 'b> {
             | Constant::RecordUpdate { .. }
             | Constant::BitArray { .. }
             | Constant::StringConcatenation { .. }
+            | Constant::BinOp { .. }
             | Constant::Invalid { .. } => (),
         }
     }
 }
 
+/// Returns the literal a constant stands for, following references to other
+/// module constants.
+///
+fn constant_literal(constant: &TypedConstant) -> &TypedConstant {
+    match constant {
+        Constant::Var {
+            constructor: Some(constructor),
+            ..
+        } => match &constructor.variant {
+            ValueConstructorVariant::ModuleConstant { literal, .. } => constant_literal(literal),
+            ValueConstructorVariant::LocalVariable { .. }
+            | ValueConstructorVariant::ModuleFn { .. }
+            | ValueConstructorVariant::Record { .. } => constant,
+        },
+
+        Constant::Int { .. }
+        | Constant::Float { .. }
+        | Constant::String { .. }
+        | Constant::Tuple { .. }
+        | Constant::List { .. }
+        | Constant::Record { .. }
+        | Constant::RecordUpdate { .. }
+        | Constant::BitArray { .. }
+        | Constant::Var { .. }
+        | Constant::StringConcatenation { .. }
+        | Constant::BinOp { .. }
+        | Constant::Invalid { .. } => constant,
+    }
+}
+
+/// Prints a float the way it would be written in Gleam source code, so that it
+/// can be used as the value of a literal. Rust leaves out the decimal point
+/// when using scientific notation, so it's added back.
+///
+fn float_literal(value: f64) -> EcoString {
+    let value = format!("{value:?}");
+    match value.split_once('e') {
+        Some((mantissa, exponent)) if !mantissa.contains('.') => {
+            eco_format!("{mantissa}.0e{exponent}")
+        }
+        _ => value.into(),
+    }
+}
+
 /// Given a constants, this will change its type into the given one, turning
 /// the constant into an `Invalid` one if necessary.
 ///
@@ -5222,7 +6313,8 @@ fn invalid_with_annotated_type(constant: TypedConstant, new_type: Arc<Type>) ->
         | Constant::Float { location, .. }
         | Constant::String { location, .. }
         | Constant::BitArray { location, .. }
-        | Constant::StringConcatenation { location, .. } => TypedConstant::Invalid {
+        | Constant::StringConcatenation { location, .. }
+        | Constant::BinOp { location, .. } => TypedConstant::Invalid {
             location,
             type_: new_type,
             extra_information: None,
@@ -5354,6 +6446,54 @@ enum ReferenceRegistration {
     },
 }
 
+/// A pattern matching on the prelude's `True` or `False` constructor. It is
+/// built already resolved so that a variant with the same name defined in the
+/// module being analysed can't be picked up in its place.
+fn bool_pattern(value: bool, location: SrcSpan) -> TypedPattern {
+    let (name, constructor_index) = if value { ("True", 0) } else { ("False", 1) };
+    Pattern::Constructor {
+        location,
+        name_location: location,
+        name: name.into(),
+        arguments: vec![],
+        module: None,
+        constructor: Inferred::Known(PatternConstructor {
+            name: name.into(),
+            field_map: None,
+            documentation: None,
+            module: PRELUDE_MODULE_NAME.into(),
+            location: SrcSpan::default(),
+            constructor_index,
+        }),
+        spread: None,
+        type_: bool_with_variant(Some(value)),
+    }
+}
+
+/// The prelude's `Nil` value, built already resolved for the same reason as
+/// `bool_pattern`.
+fn nil_value(location: SrcSpan) -> TypedExpr {
+    TypedExpr::Var {
+        location,
+        name: "Nil".into(),
+        constructor: ValueConstructor {
+            publicity: Publicity::Public,
+            deprecation: Deprecation::NotDeprecated,
+            variant: ValueConstructorVariant::Record {
+                documentation: None,
+                module: PRELUDE_MODULE_NAME.into(),
+                name: "Nil".into(),
+                arity: 0,
+                field_map: None,
+                location: SrcSpan::default(),
+                variants_count: 1,
+                variant_index: 0,
+            },
+            type_: nil(),
+        },
+    }
+}
+
 fn extract_typed_use_call_assignments(
     call: &TypedExpr,
     assignments_count: usize,
@@ -5571,7 +6711,7 @@ impl UseAssignments {
                 Pattern::Discard { name, .. } => assignments.function_arguments.push(Arg {
                     location,
                     names: ArgNames::Discard { name, location },
-                    annotation: None,
+                    annotation,
                     type_: (),
                 }),
 
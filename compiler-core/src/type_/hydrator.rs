@@ -167,10 +167,11 @@ impl Hydrator {
 
                 match deprecation {
                     Deprecation::NotDeprecated => {}
-                    Deprecation::Deprecated { message } => {
+                    Deprecation::Deprecated { message, since } => {
                         problems.warning(Warning::DeprecatedItem {
                             location: *location,
                             message: message.clone(),
+                            since: since.clone(),
                             layer: Layer::Type,
                         })
                     }
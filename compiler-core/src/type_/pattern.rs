@@ -1168,15 +1168,31 @@ impl<'a, 'b> PatternTyper<'a, 'b> {
 
                 match constructor_deprecation {
                     Deprecation::NotDeprecated => {}
-                    Deprecation::Deprecated { message } => {
+                    Deprecation::Deprecated { message, since } => {
                         self.problems.warning(Warning::DeprecatedItem {
                             location,
                             message: message.clone(),
+                            since: since.clone(),
                             layer: Layer::Value,
                         })
                     }
                 }
 
+                if let Some(package) = self
+                    .environment
+                    .internal_variant_package(
+                        &constructor_type,
+                        pattern_constructor.constructor_index,
+                    )
+                    .cloned()
+                {
+                    self.error(Error::InternalVariantFromOtherPackage {
+                        location,
+                        name: pattern_constructor.name.clone(),
+                        package,
+                    });
+                }
+
                 self.environment.references.register_value_reference(
                     pattern_constructor.module.clone(),
                     pattern_constructor.name.clone(),
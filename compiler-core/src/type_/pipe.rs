@@ -188,12 +188,15 @@ impl<'a, 'b, 'c> PipeTyper<'a, 'b, 'c> {
                 UntypedExpr::Int { .. }
                 | UntypedExpr::Float { .. }
                 | UntypedExpr::String { .. }
+                | UntypedExpr::InterpolatedString { .. }
                 | UntypedExpr::Block { .. }
                 | UntypedExpr::Var { .. }
                 | UntypedExpr::List { .. }
                 | UntypedExpr::BinOp { .. }
                 | UntypedExpr::PipeLine { .. }
                 | UntypedExpr::Case { .. }
+                | UntypedExpr::If { .. }
+                | UntypedExpr::SubjectlessCase { .. }
                 | UntypedExpr::FieldAccess { .. }
                 | UntypedExpr::Tuple { .. }
                 | UntypedExpr::TupleIndex { .. }
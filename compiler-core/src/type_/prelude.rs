@@ -274,11 +274,13 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                                 name: "True".into(),
                                 parameters: vec![],
                                 documentation: None,
+                                publicity: Publicity::Public,
                             },
                             TypeValueConstructor {
                                 name: "False".into(),
                                 parameters: vec![],
                                 documentation: None,
+                                publicity: Publicity::Public,
                             },
                         ],
                         opaque: Opaque::NotOpaque,
@@ -413,6 +415,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                             name: "Nil".into(),
                             parameters: vec![],
                             documentation: None,
+                            publicity: Publicity::Public,
                         }],
                         opaque: Opaque::NotOpaque,
                     },
@@ -449,6 +452,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                                     documentation: None,
                                 }],
                                 documentation: None,
+                                publicity: Publicity::Public,
                             },
                             TypeValueConstructor {
                                 name: "Error".into(),
@@ -458,6 +462,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                                     documentation: None,
                                 }],
                                 documentation: None,
+                                publicity: Publicity::Public,
                             },
                         ],
                         opaque: Opaque::NotOpaque,
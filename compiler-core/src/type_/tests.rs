@@ -29,10 +29,13 @@ mod exhaustiveness;
 mod externals;
 mod functions;
 mod guards;
+mod if_;
 mod imports;
 mod let_assert;
+mod let_else;
 mod pipes;
 mod pretty;
+mod subjectless_case;
 mod target_implementations;
 mod type_alias;
 mod use_;
@@ -57,6 +60,14 @@ macro_rules! assert_module_infer {
         assert_eq!(($src, constructors), ($src, expected));
     };
 
+    ($(($package:literal, $name:expr, $module_src:literal)),+, $src:literal, $module:expr $(,)?) => {
+        let constructors =
+            $crate::type_::tests::infer_module($src, vec![$(($package, $name, $module_src)),*]);
+        let expected = $crate::type_::tests::stringify_tuple_strs($module);
+
+        assert_eq!(($src, constructors), ($src, expected));
+    };
+
     ($src:expr, $module:expr $(,)?) => {{
         let constructors = $crate::type_::tests::infer_module($src, vec![]);
         let expected = $crate::type_::tests::stringify_tuple_strs($module);
@@ -615,7 +626,7 @@ pub fn syntax_error(src: &str) -> String {
     let error = Error::Parse {
         src: src.into(),
         path: Utf8PathBuf::from("/src/one/two.gleam"),
-        error: Box::new(error),
+        errors: error.errors,
     };
     error.pretty_string()
 }
@@ -810,11 +821,13 @@ fn infer_module_type_retention_test() {
                                 name: "True".into(),
                                 parameters: vec![],
                                 documentation: None,
+                                publicity: Publicity::Public,
                             },
                             TypeValueConstructor {
                                 name: "False".into(),
                                 parameters: vec![],
                                 documentation: None,
+                                publicity: Publicity::Public,
                             }
                         ],
                         opaque: Opaque::NotOpaque,
@@ -841,6 +854,7 @@ fn infer_module_type_retention_test() {
                                     documentation: None,
                                 }],
                                 documentation: None,
+                                publicity: Publicity::Public,
                             },
                             TypeValueConstructor {
                                 name: "Error".into(),
@@ -850,6 +864,7 @@ fn infer_module_type_retention_test() {
                                     documentation: None,
                                 }],
                                 documentation: None,
+                                publicity: Publicity::Public,
                             }
                         ],
                         opaque: Opaque::NotOpaque,
@@ -863,6 +878,7 @@ fn infer_module_type_retention_test() {
                             name: "Nil".into(),
                             parameters: vec![],
                             documentation: None,
+                            publicity: Publicity::Public,
                         }],
                         opaque: Opaque::NotOpaque,
                     }
@@ -1029,6 +1045,10 @@ fn expr_fn() {
     );
 
     assert_infer!("let add = fn(x, y) { x + y } add(_, 2)", "fn(Int) -> Int");
+    assert_infer!(
+        "let f = fn(x, y, z) { #(x, y, z) } f(1, _, 2.0)",
+        "fn(a) -> #(Int, a, Float)",
+    );
     assert_infer!("fn(x) { #(1, x) }", "fn(a) -> #(Int, a)");
     assert_infer!("fn(x, y) { #(x, y) }", "fn(a, b) -> #(a, b)");
     assert_infer!("fn(x) { #(x, x) }", "fn(a) -> #(a, a)");
@@ -2328,6 +2348,41 @@ fn const_record_update() {
     );
 }
 
+#[test]
+fn const_arithmetic() {
+    assert_module_infer!(
+        "pub const kib = 1024
+        pub const size = 4 * kib + 2
+        pub const ratio = 1.0 /. 3.0",
+        vec![("kib", "Int"), ("ratio", "Float"), ("size", "Int")],
+    );
+}
+
+#[test]
+fn const_division_by_zero() {
+    assert_module_error!("pub const size = 4 / 0");
+}
+
+#[test]
+fn const_float_division_by_zero() {
+    assert_module_error!("pub const ratio = 1.0 /. 0.0");
+}
+
+#[test]
+fn const_arithmetic_mixed_int_and_float() {
+    assert_module_error!("pub const size = 1 + 2.0");
+}
+
+#[test]
+fn const_arithmetic_float_out_of_range() {
+    assert_module_error!("pub const ratio = 1.0e308 *. 10.0");
+}
+
+#[test]
+fn const_arithmetic_javascript_unsafe_int() {
+    assert_js_warning!("pub const size = 9_007_199_254_740_991 + 1");
+}
+
 #[test]
 fn const_record_update_all_fields() {
     assert_warning!(
@@ -2500,6 +2555,148 @@ fn generic_unlabelled_field_in_updated_const_record_wrong_type() {
     );
 }
 
+#[test]
+fn const_record_update_label_shorthand() {
+    assert_module_infer!(
+        "pub type Person {
+            Person(name: String, age: Int)
+        }
+
+        pub const name = \"Bob\"
+        pub const alice = Person(\"Alice\", 30)
+        pub const bob = Person(..alice, name:)",
+        vec![
+            ("Person", "fn(String, Int) -> Person"),
+            ("alice", "Person"),
+            ("bob", "Person"),
+            ("name", "String"),
+        ]
+    );
+}
+
+#[test]
+fn const_record_update_label_shorthand_unknown_constant() {
+    assert_module_error!(
+        "pub type Person {
+            Person(name: String, age: Int)
+        }
+
+        const alice = Person(\"Alice\", 30)
+        const bob = Person(..alice, name:)"
+    );
+}
+
+#[test]
+fn const_record_label_shorthand_unknown_constant() {
+    assert_module_error!(
+        "pub type Person {
+            Person(name: String, age: Int)
+        }
+
+        const age = 30
+        const alice = Person(name:, age:)"
+    );
+}
+
+#[test]
+fn const_record_update_imported_record() {
+    assert_module_infer!(
+        (
+            "other",
+            "pub type Person { Person(name: String, age: Int) }
+            pub const alice = Person(\"Alice\", 30)"
+        ),
+        "import other
+        pub const bob = other.Person(..other.alice, name: \"Bob\")",
+        vec![("bob", "Person")],
+    );
+}
+
+#[test]
+fn const_record_update_imported_record_unqualified_constructor() {
+    assert_module_infer!(
+        (
+            "other",
+            "pub type Person { Person(name: String, age: Int) }
+            pub const alice = Person(\"Alice\", 30)"
+        ),
+        "import other.{Person}
+        pub const bob = Person(..other.alice, age: 31)",
+        vec![("bob", "Person")],
+    );
+}
+
+#[test]
+fn const_record_update_imported_constant_alias() {
+    assert_module_infer!(
+        (
+            "other",
+            "pub type Person { Person(name: String, age: Int) }
+            const alice = Person(\"Alice\", 30)
+            pub const default = alice"
+        ),
+        "import other
+        pub const bob = other.Person(..other.default, name: \"Bob\")",
+        vec![("bob", "Person")],
+    );
+}
+
+#[test]
+fn const_record_update_imported_record_different_type() {
+    assert_module_error!(
+        (
+            "other",
+            "pub type Person { Person(name: String, age: Int) }
+            pub const alice = Person(\"Alice\", 30)"
+        ),
+        "import other
+        pub type Person { Person(name: String, age: Int) }
+        pub const bob = Person(..other.alice, name: \"Bob\")"
+    );
+}
+
+#[test]
+fn const_record_update_imported_record_wrong_variant() {
+    assert_module_error!(
+        (
+            "other",
+            "pub type Pet { Dog(name: String) Cat(name: String) }
+            pub const rex = Dog(\"Rex\")"
+        ),
+        "import other
+        pub const tom = other.Cat(..other.rex, name: \"Tom\")"
+    );
+}
+
+#[test]
+fn const_record_update_imported_record_referencing_private_function() {
+    assert_module_error!(
+        (
+            "other",
+            "pub type Wibble { Wibble(a: Int, f: fn() -> Int) }
+            fn one() { 1 }
+            pub const wibble = Wibble(1, one)"
+        ),
+        "import other
+        pub const w = other.Wibble(..other.wibble, a: 2)"
+    );
+}
+
+#[test]
+fn const_record_update_imported_record_referencing_unimported_module() {
+    assert_module_error!(
+        ("third", "pub fn one() { 1 }"),
+        (
+            "other",
+            "import third.{one}
+            pub type Wibble { Wibble(a: Int, f: fn() -> Int) }
+            pub const wibble = Wibble(1, one)"
+        ),
+        "import other
+        pub const w = other.Wibble(..other.wibble, a: 2)"
+    );
+}
+
 #[test]
 fn module_constant_functions() {
     assert_module_infer!(
@@ -2734,6 +2931,24 @@ fn string_concat_ko_2() {
     assert_error!(r#" 1 <> "2" "#);
 }
 
+#[test]
+fn string_interpolation_ok() {
+    assert_infer!(r#"let name = "Joe" "Hello, \{name}!""#, "String");
+}
+
+#[test]
+fn string_interpolation_infers_string_argument() {
+    assert_module_infer!(
+        r#"pub fn greet(name) { "Hello, \{name}!" }"#,
+        vec![("greet", "fn(String) -> String")],
+    );
+}
+
+#[test]
+fn string_interpolation_ko() {
+    assert_error!(r#" "There are \{1} items" "#);
+}
+
 // https://github.com/gleam-lang/gleam/issues/1087
 #[test]
 fn generic_inner_access() {
@@ -2967,6 +3182,7 @@ fn assert_suitable_main_function_wrong_arity() {
                 can_run_on_javascript: true,
             },
             purity: Purity::Pure,
+            must_use: false,
         },
     };
     assert!(
@@ -2998,6 +3214,7 @@ fn assert_suitable_main_function_ok() {
                 can_run_on_javascript: true,
             },
             purity: Purity::Pure,
+            must_use: false,
         },
     };
     assert!(
@@ -3029,6 +3246,7 @@ fn assert_suitable_main_function_erlang_not_supported() {
                 can_run_on_javascript: true,
             },
             purity: Purity::Impure,
+            must_use: false,
         },
     };
     assert!(
@@ -3060,6 +3278,7 @@ fn assert_suitable_main_function_javascript_not_supported() {
                 can_run_on_javascript: false,
             },
             purity: Purity::Impure,
+            must_use: false,
         },
     };
     assert!(
@@ -1,4 +1,4 @@
-use crate::assert_module_infer;
+use crate::{assert_module_error, assert_module_infer};
 
 #[test]
 fn bug_3629() {
@@ -24,3 +24,221 @@ pub fn main() {
         ],
     );
 }
+
+#[test]
+fn positional_access_single_variant() {
+    assert_module_infer!(
+        r#"
+pub type Cat {
+  Cat(String, Int)
+}
+
+pub fn main() {
+  let cat = Cat("Nubi", 3)
+  #(cat.0, cat.1)
+}
+"#,
+        vec![
+            ("Cat", "fn(String, Int) -> Cat"),
+            ("main", "fn() -> #(String, Int)")
+        ],
+    );
+}
+
+#[test]
+fn positional_access_on_constructor_call() {
+    assert_module_infer!(
+        r#"
+pub type Cat {
+  Cat(String, Int)
+}
+
+pub fn main() {
+  Cat("Nubi", 3).0
+}
+"#,
+        vec![
+            ("Cat", "fn(String, Int) -> Cat"),
+            ("main", "fn() -> String")
+        ],
+    );
+}
+
+#[test]
+fn positional_access_generic_type() {
+    assert_module_infer!(
+        r#"
+pub type Box(a) {
+  Box(a)
+}
+
+pub fn main(box: Box(Int)) {
+  box.0
+}
+"#,
+        vec![("Box", "fn(a) -> Box(a)"), ("main", "fn(Box(Int)) -> Int")],
+    );
+}
+
+#[test]
+fn positional_access_skips_labelled_fields() {
+    assert_module_infer!(
+        r#"
+pub type Cat {
+  Cat(String, age: Int)
+}
+
+pub fn main(cat: Cat) {
+  cat.0
+}
+"#,
+        vec![
+            ("Cat", "fn(String, Int) -> Cat"),
+            ("main", "fn(Cat) -> String")
+        ],
+    );
+}
+
+#[test]
+fn positional_access_imported_type() {
+    assert_module_infer!(
+        ("cat", "pub type Cat { Cat(String, Int) }"),
+        r#"
+import cat
+
+pub fn main(kitty: cat.Cat) {
+  kitty.1
+}
+"#,
+        vec![("main", "fn(Cat) -> Int")],
+    );
+}
+
+#[test]
+fn positional_access_inferred_variant() {
+    assert_module_infer!(
+        r#"
+pub type Pet {
+  Cat(String)
+  Dog(Int, Float)
+}
+
+pub fn main() {
+  let pet = Dog(1, 2.0)
+  pet.1
+}
+"#,
+        vec![
+            ("Cat", "fn(String) -> Pet"),
+            ("Dog", "fn(Int, Float) -> Pet"),
+            ("main", "fn() -> Float")
+        ],
+    );
+}
+
+#[test]
+fn positional_access_variant_inferred_by_case() {
+    assert_module_infer!(
+        r#"
+pub type Pet {
+  Cat(String)
+  Dog(Int, String)
+}
+
+pub fn main(pet: Pet) {
+  case pet {
+    Cat(..) as cat -> cat.0
+    Dog(..) as dog -> dog.1
+  }
+}
+"#,
+        vec![
+            ("Cat", "fn(String) -> Pet"),
+            ("Dog", "fn(Int, String) -> Pet"),
+            ("main", "fn(Pet) -> String")
+        ],
+    );
+}
+
+#[test]
+fn positional_access_out_of_bounds() {
+    assert_module_error!(
+        r#"
+pub type Cat {
+  Cat(String, Int)
+}
+
+pub fn main(cat: Cat) {
+  cat.2
+}
+"#
+    );
+}
+
+#[test]
+fn positional_access_no_unlabelled_fields() {
+    assert_module_error!(
+        r#"
+pub type Cat {
+  Cat(name: String)
+}
+
+pub fn main(cat: Cat) {
+  cat.0
+}
+"#
+    );
+}
+
+#[test]
+fn positional_access_multiple_variants() {
+    assert_module_error!(
+        r#"
+pub type Pet {
+  Cat(String)
+  Dog(String)
+}
+
+pub fn main(pet: Pet) {
+  pet.0
+}
+"#
+    );
+}
+
+#[test]
+fn positional_access_multiple_variants_from_function_call() {
+    assert_module_error!(
+        r#"
+pub type Pet {
+  Cat(String)
+  Dog(String)
+}
+
+fn pet() -> Pet {
+  Cat("Nubi")
+}
+
+pub fn main() {
+  pet().0
+}
+"#
+    );
+}
+
+#[test]
+fn positional_access_out_of_bounds_for_inferred_variant() {
+    assert_module_error!(
+        r#"
+pub type Pet {
+  Cat(String)
+  Dog(Int, Float)
+}
+
+pub fn main() {
+  let pet = Cat("Nubi")
+  pet.1
+}
+"#
+    );
+}
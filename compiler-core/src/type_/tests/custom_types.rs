@@ -220,3 +220,125 @@ fn handle_fish(fish: Fish) {
 "#
     );
 }
+
+#[test]
+fn internal_variant_can_be_used_in_its_module() {
+    assert_module_infer!(
+        "
+pub type Wibble {
+  Wibble
+  @internal
+  Wobble
+}
+
+pub fn main(wibble: Wibble) {
+  case wibble {
+    Wibble -> Wobble
+    Wobble -> Wibble
+  }
+}
+",
+        vec![
+            ("Wibble", "Wibble"),
+            ("Wobble", "Wibble"),
+            ("main", "fn(Wibble) -> Wibble")
+        ],
+    );
+}
+
+#[test]
+fn internal_variant_can_be_used_in_its_package() {
+    assert_module_infer!(
+        ("wibble", "pub type Wibble { Wibble @internal Wobble }"),
+        "
+import wibble
+
+pub fn main(w: wibble.Wibble) {
+  case w {
+    wibble.Wibble -> wibble.Wobble
+    wibble.Wobble -> wibble.Wibble
+  }
+}
+",
+        vec![("main", "fn(Wibble) -> Wibble")],
+    );
+}
+
+#[test]
+fn internal_variant_cannot_be_constructed_in_another_package() {
+    assert_module_error!(
+        (
+            "other_package",
+            "wibble",
+            "pub type Wibble { Wibble @internal Wobble }"
+        ),
+        "
+import wibble
+
+pub fn main() {
+  wibble.Wobble
+}
+"
+    );
+}
+
+#[test]
+fn unqualified_internal_variant_cannot_be_constructed_in_another_package() {
+    assert_module_error!(
+        (
+            "other_package",
+            "wibble",
+            "pub type Wibble { Wibble @internal Wobble }"
+        ),
+        "
+import wibble.{Wobble}
+
+pub fn main() {
+  Wobble
+}
+"
+    );
+}
+
+#[test]
+fn internal_variant_cannot_be_matched_on_in_another_package() {
+    assert_module_error!(
+        (
+            "other_package",
+            "wibble",
+            "pub type Wibble { Wibble @internal Wobble }"
+        ),
+        "
+import wibble
+
+pub fn main(w: wibble.Wibble) {
+  case w {
+    wibble.Wobble -> 1
+    _ -> 2
+  }
+}
+"
+    );
+}
+
+#[test]
+fn public_variant_of_type_with_internal_variant_can_be_used_in_another_package() {
+    assert_module_infer!(
+        (
+            "other_package",
+            "wibble",
+            "pub type Wibble { Wibble @internal Wobble }"
+        ),
+        "
+import wibble
+
+pub fn main(w: wibble.Wibble) {
+  case w {
+    wibble.Wibble -> wibble.Wibble
+    _ -> wibble.Wibble
+  }
+}
+",
+        vec![("main", "fn(Wibble) -> Wibble")],
+    );
+}
@@ -2379,7 +2379,7 @@ const list_of_bools = [True, False, gleam.False]
 #[test]
 fn qualified_type_not_a_tuple() {
     assert_module_error!(
-        ("mod", "pub type Pair(a, b) { Pair(a, b) }"),
+        ("mod", "pub opaque type Pair(a, b) { Pair(a, b) }"),
         "
 import mod.{type Pair as Duo}
 pub fn first(pair: Duo(a, b)) {
@@ -2577,6 +2577,21 @@ pub fn main(wibble: wibble.Wibble) {
     );
 }
 
+#[test]
+fn const_record_update_wrong_variant() {
+    assert_module_error!(
+        r#"
+pub type Pet {
+  Dog(name: String, age: Int)
+  Cat(name: String, age: Int)
+}
+
+pub const dog = Dog("Rex", 3)
+pub const cat = Cat(..dog, name: "Tom")
+"#
+    );
+}
+
 #[test]
 fn inferred_variant_record_update_change_type_parameter_different_branches() {
     assert_module_error!(
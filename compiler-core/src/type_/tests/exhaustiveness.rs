@@ -844,6 +844,55 @@ pub fn main(x) {
     );
 }
 
+#[test]
+fn redundant_after_variable_pattern() {
+    assert_warning!(
+        r#"
+pub fn main(x) {
+  case x {
+    y -> y
+    1 -> 2
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn not_redundant_after_guarded_catch_all() {
+    assert_no_warnings!(
+        r#"
+pub fn main(x) {
+  case x {
+    _ if x > 0 -> 0
+    1 -> 2
+    _ -> 3
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn internal_variant_from_another_package_is_not_listed_as_missing() {
+    assert_module_error!(
+        (
+            "other_package",
+            "wibble",
+            "pub type Wibble { Wibble @internal Wobble }"
+        ),
+        "
+import wibble
+
+pub fn main(w: wibble.Wibble) {
+  case w {
+    wibble.Wibble -> 1
+  }
+}
+"
+    );
+}
+
 //https://github.com/gleam-lang/gleam/issues/2651
 #[test]
 fn redundant_3() {
@@ -0,0 +1,108 @@
+use crate::{assert_infer, assert_module_error, assert_module_infer};
+
+#[test]
+fn if_else() {
+    assert_infer!("if True { 1 } else { 2 }", "Int");
+}
+
+#[test]
+fn if_else_if() {
+    assert_infer!(
+        r#"if 1 > 2 { "a" } else if 2 > 3 { "b" } else { "c" }"#,
+        "String"
+    );
+}
+
+#[test]
+fn if_without_else_is_nil() {
+    assert_infer!("if True { Nil }", "Nil");
+}
+
+#[test]
+fn if_with_panicking_branch() {
+    assert_module_infer!(
+        r#"
+pub fn main(x) {
+  if x > 0 { x } else { panic }
+}
+"#,
+        vec![("main", "fn(Int) -> Int")]
+    );
+}
+
+#[test]
+fn if_condition_must_be_bool() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  if 1 { 2 } else { 3 }
+}
+"#
+    );
+}
+
+#[test]
+fn if_branches_must_have_the_same_type() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  if True { 1 } else { "wibble" }
+}
+"#
+    );
+}
+
+#[test]
+fn if_without_else_used_as_a_value() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  let a = if True { 1 }
+  a
+}
+"#
+    );
+}
+
+#[test]
+fn variables_bound_in_if_branch_are_not_in_scope_after_it() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  if True {
+    let x = 1
+    Nil
+  }
+  x
+}
+"#
+    );
+}
+
+#[test]
+fn if_uses_prelude_constructors_when_they_are_shadowed() {
+    assert_module_infer!(
+        r#"
+pub type Wibble {
+  True
+  False
+  Nil
+}
+
+pub fn main(x: Bool) {
+  if x { 1 } else { 2 }
+}
+
+pub fn without_else(x: Bool) {
+  if x { panic }
+}
+"#,
+        vec![
+            ("False", "Wibble"),
+            ("Nil", "Wibble"),
+            ("True", "Wibble"),
+            ("main", "fn(Bool) -> Int"),
+            ("without_else", "fn(Bool) -> Nil"),
+        ]
+    );
+}
@@ -0,0 +1,106 @@
+use crate::{assert_module_error, assert_module_infer, assert_no_warnings, assert_warning};
+
+#[test]
+fn let_else_binds_variables() {
+    assert_module_infer!(
+        r#"
+pub fn main(x) {
+  let Ok(y) = x else { panic }
+  y + 1
+}
+"#,
+        vec![("main", "fn(Result(Int, a)) -> Int")]
+    );
+}
+
+#[test]
+fn let_else_with_annotation() {
+    assert_module_infer!(
+        r#"
+pub fn main(x) {
+  let Ok(y): Result(String, Nil) = x else { todo }
+  y
+}
+"#,
+        vec![("main", "fn(Result(String, Nil)) -> String")]
+    );
+}
+
+#[test]
+fn let_else_as_last_statement_returns_nil() {
+    assert_module_infer!(
+        r#"
+pub fn main(x) {
+  let Ok(_) = x else { panic }
+}
+"#,
+        vec![("main", "fn(Result(a, b)) -> Nil")]
+    );
+}
+
+#[test]
+fn let_else_variables_are_not_in_scope_in_else_body() {
+    assert_module_error!(
+        r#"
+pub fn main(x) {
+  let Ok(y) = x else { panic as y }
+  y
+}
+"#
+    );
+}
+
+#[test]
+fn let_else_body_must_diverge() {
+    assert_module_error!(
+        r#"
+pub fn main(x) {
+  let Ok(y) = x else { 1 }
+  y
+}
+"#
+    );
+}
+
+#[test]
+fn let_else_body_with_panicking_last_statement() {
+    assert_no_warnings!(
+        r#"
+pub fn main(x) {
+  let Ok(y) = x else {
+    let message = "not ok"
+    panic as message
+  }
+  y
+}
+"#
+    );
+}
+
+#[test]
+fn redundant_let_else() {
+    assert_warning!(
+        r#"
+pub fn main(x) {
+  let #(a, b) = x else { panic }
+  a + b
+}
+"#
+    );
+}
+
+#[test]
+fn let_else_as_last_statement_returns_prelude_nil_when_it_is_shadowed() {
+    assert_module_infer!(
+        r#"
+pub type Wibble {
+  Nil
+}
+
+pub fn main(x) {
+  let Ok(_) = x else { panic }
+}
+"#,
+        vec![("Nil", "Wibble"), ("main", "fn(Result(a, b)) -> Nil")]
+    );
+}
@@ -0,0 +1,118 @@
+use crate::{assert_infer, assert_module_error, assert_module_infer, assert_warning};
+
+#[test]
+fn subjectless_case() {
+    assert_infer!(r#"case { 1 > 2 -> "a" 2 > 3 -> "b" _ -> "c" }"#, "String");
+}
+
+#[test]
+fn subjectless_case_with_only_a_catch_all() {
+    assert_infer!("case { _ -> 1 }", "Int");
+}
+
+#[test]
+fn case_with_block_subject() {
+    assert_infer!("case { 1 } { _ -> 2 }", "Int");
+}
+
+#[test]
+fn case_with_block_subject_starting_with_assignment() {
+    assert_infer!("case { let x = 1 x + 1 } { 2 -> True _ -> False }", "Bool");
+}
+
+#[test]
+fn subjectless_case_with_panicking_clause() {
+    assert_module_infer!(
+        r#"
+pub fn main(x) {
+  case {
+    x > 0 -> x
+    _ -> panic
+  }
+}
+"#,
+        vec![("main", "fn(Int) -> Int")]
+    );
+}
+
+#[test]
+fn subjectless_case_condition_must_be_bool() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  case {
+    1 -> 2
+    _ -> 3
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn subjectless_case_clauses_must_have_the_same_type() {
+    assert_module_error!(
+        r#"
+pub fn main() {
+  case {
+    True -> 1
+    _ -> "wibble"
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn subjectless_case_without_catch_all() {
+    assert_module_error!(
+        r#"
+pub fn main(x) {
+  case {
+    x > 0 -> 1
+    x < 0 -> 2
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn subjectless_case_clauses_after_catch_all_are_unreachable() {
+    assert_warning!(
+        r#"
+pub fn main(x) {
+  case {
+    x > 0 -> 1
+    _ -> 2
+    x < 0 -> 3
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn subjectless_case_uses_prelude_constructors_when_they_are_shadowed() {
+    assert_module_infer!(
+        r#"
+pub type Wibble {
+  True
+  False
+}
+
+pub fn main(x: Int) {
+  case {
+    x > 0 -> 1
+    x < 0 -> 2
+    _ -> 3
+  }
+}
+"#,
+        vec![
+            ("False", "Wibble"),
+            ("True", "Wibble"),
+            ("main", "fn(Int) -> Int")
+        ]
+    );
+}
@@ -252,6 +252,41 @@ use <- z()
     );
 }
 
+#[test]
+fn discard_with_wrong_annotation() {
+    assert_error!(
+        r#"
+let x = fn(f) { "Hello, " <> f(1) }
+use _: String <- x()
+"Giacomo!"
+"#
+    );
+}
+
+#[test]
+fn discard_with_annotation() {
+    assert_infer!(
+        r#"
+let x = fn(f) { "Hello, " <> f(1) }
+use _: Int <- x()
+"Giacomo!"
+"#,
+        "String"
+    );
+}
+
+#[test]
+fn variable_with_annotation() {
+    assert_infer!(
+        r#"
+let x = fn(f) { "Hello, " <> f(1) }
+use n: Int <- x()
+"Giacomo!"
+"#,
+        "String"
+    );
+}
+
 #[test]
 fn wrong_callback_arity() {
     assert_error!(
@@ -42,6 +42,20 @@ pub fn wibble() {}
     assert_eq!(version, Version::new(1, 1, 0));
 }
 
+#[test]
+fn internal_annotation_on_variant_requires_v1_15() {
+    let version = infer_version(
+        "
+pub type Wibble {
+  Wibble
+  @internal
+  Wobble
+}
+",
+    );
+    assert_eq!(version, Version::new(1, 15, 0));
+}
+
 #[test]
 fn nested_tuple_access_requires_v1_1() {
     let version = infer_version(
@@ -411,3 +425,100 @@ pub fn main(x) {
     );
     assert_eq!(version, Version::new(1, 12, 0));
 }
+
+#[test]
+fn if_expression_requires_v1_15() {
+    let version = infer_version(
+        "
+pub fn main(x) {
+  if x { 1 } else { 2 }
+}",
+    );
+    assert_eq!(version, Version::new(1, 15, 0));
+}
+
+#[test]
+fn let_else_requires_v1_15() {
+    let version = infer_version(
+        "
+pub fn main(x) {
+  let Ok(y) = x else { panic }
+  y
+}",
+    );
+    assert_eq!(version, Version::new(1, 15, 0));
+}
+
+#[test]
+fn constant_arithmetic_requires_v1_15() {
+    let version = infer_version(
+        "
+pub const size = 4 * 1024
+",
+    );
+    assert_eq!(version, Version::new(1, 15, 0));
+}
+
+#[test]
+fn string_interpolation_requires_v1_15() {
+    let version = infer_version(
+        r#"
+pub fn main(name) {
+  "Hello, \{name}!"
+}"#,
+    );
+    assert_eq!(version, Version::new(1, 15, 0));
+}
+
+#[test]
+fn subjectless_case_requires_v1_15() {
+    let version = infer_version(
+        "
+pub fn main(x) {
+  case {
+    x > 0 -> 1
+    _ -> 2
+  }
+}",
+    );
+    assert_eq!(version, Version::new(1, 15, 0));
+}
+
+#[test]
+fn positional_field_access_requires_v1_15() {
+    let version = infer_version(
+        "
+pub type Cat {
+  Cat(String, Int)
+}
+
+pub fn main(cat: Cat) {
+  cat.0
+}",
+    );
+    assert_eq!(version, Version::new(1, 15, 0));
+}
+
+#[test]
+fn must_use_annotation_requires_v1_15() {
+    let version = infer_version(
+        "
+@must_use
+pub fn wibble() {
+  1
+}",
+    );
+    assert_eq!(version, Version::new(1, 15, 0));
+}
+
+#[test]
+fn deprecation_since_requires_v1_15() {
+    let version = infer_version(
+        r#"
+@deprecated("Use wobble instead", since: "1.2.0")
+pub fn wibble() {
+  1
+}"#,
+    );
+    assert_eq!(version, Version::new(1, 15, 0));
+}
@@ -92,6 +92,78 @@ pub fn main() { let _ = wibble() 5 }",
     );
 }
 
+#[test]
+fn must_use_function_result_discarded() {
+    assert_warning!(
+        "
+@must_use
+pub fn wibble() -> Int { 1 }
+
+pub fn main() {
+  wibble()
+  Nil
+}"
+    );
+}
+
+#[test]
+fn must_use_qualified_function_result_discarded() {
+    assert_warning!(
+        ("wibble", "@must_use pub fn wibble() -> Int { 1 }"),
+        "
+import wibble
+
+pub fn main() {
+  wibble.wibble()
+  Nil
+}"
+    );
+}
+
+#[test]
+fn must_use_function_result_piped_onward() {
+    assert_no_warnings!(
+        "
+@must_use
+pub fn wibble() -> Int { 1 }
+
+@external(erlang, \"io\", \"write\")
+fn log(x: Int) -> Nil
+
+pub fn main() {
+  wibble() |> log
+  Nil
+}"
+    );
+}
+
+#[test]
+fn must_use_function_result_returned() {
+    assert_no_warnings!(
+        "
+@must_use
+pub fn wibble() -> Int { 1 }
+
+pub fn main() {
+  wibble()
+}"
+    );
+}
+
+#[test]
+fn must_use_function_result_explicitly_discarded() {
+    assert_no_warnings!(
+        "
+@must_use
+pub fn wibble() -> Int { 1 }
+
+pub fn main() {
+  let _ = wibble()
+  Nil
+}"
+    );
+}
+
 #[test]
 fn unused_int() {
     assert_warning!("pub fn main() { 1 2 }");
@@ -949,6 +1021,22 @@ pub fn b() {
     );
 }
 
+#[test]
+fn deprecated_function_with_since() {
+    assert_warning!(
+        r#"
+@deprecated("Don't use this!", since: "1.4.0")
+pub fn a() {
+  Nil
+}
+
+pub fn b() {
+  a
+}
+        "#
+    );
+}
+
 #[test]
 fn deprecated_imported_function() {
     assert_warning!(
@@ -869,9 +869,15 @@ Run this command to add it to your dependencies:
                 type_::Warning::DeprecatedItem {
                     location,
                     message,
+                    since,
                     layer,
                 } => {
-                    let text = wrap(&format!("It was deprecated with this message: {message}"));
+                    let text = match since {
+                        Some(since) => wrap(&format!(
+                            "It was deprecated in version {since} with this message: {message}"
+                        )),
+                        None => wrap(&format!("It was deprecated with this message: {message}")),
+                    };
                     let (title, diagnostic_label_text) = if layer.is_value() {
                         (
                             "Deprecated value used".into(),
@@ -1041,6 +1047,27 @@ variable, or delete the expression entirely if it's not needed.",
                     }),
                 },
 
+                type_::Warning::UnusedMustUseValue { location } => Diagnostic {
+                    title: "Unused return value".into(),
+                    text: wrap(
+                        "The function called here has the `@must_use` attribute, \
+so the value it returns is expected to be used.",
+                    ),
+                    hint: Some(
+                        "If you are sure you don't need it you can assign it to `_`.".into(),
+                    ),
+                    level: diagnostic::Level::Warning,
+                    location: Some(Location {
+                        path: path.to_path_buf(),
+                        src: src.clone(),
+                        label: diagnostic::Label {
+                            text: Some("This value is never used".into()),
+                            span: *location,
+                        },
+                        extra_labels: Vec::new(),
+                    }),
+                },
+
                 type_::Warning::InternalTypeLeak { location, leaked } => {
                     let mut printer = Printer::new();
 
@@ -1091,6 +1118,23 @@ hidden from the package's documentation.",
                     }),
                 },
 
+                type_::Warning::RedundantLetElse { location } => Diagnostic {
+                    title: "Redundant else".into(),
+                    text: "This else is redundant since the pattern covers all possibilities."
+                        .into(),
+                    hint: None,
+                    level: diagnostic::Level::Warning,
+                    location: Some(Location {
+                        label: diagnostic::Label {
+                            text: Some("You can remove this".into()),
+                            span: *location,
+                        },
+                        path: path.clone(),
+                        src: src.clone(),
+                        extra_labels: vec![],
+                    }),
+                },
+
                 type_::Warning::AssertAssignmentOnImpossiblePattern { location, reason } => {
                     let extra_labels = match reason {
                         AssertImpossiblePattern::InferredVariant => vec![],
@@ -1293,6 +1337,25 @@ See: https://tour.gleam.run/functions/pipelines/",
                         FeatureKind::ConstantRecordUpdate => {
                             "The record update syntax for constants was"
                         }
+                        FeatureKind::IfExpression => "The `if` expression was",
+                        FeatureKind::LetElse => "The `let else` assignment was",
+                        FeatureKind::ConstantArithmetic => {
+                            "Arithmetic operations in constants were"
+                        }
+                        FeatureKind::StringInterpolation => "String interpolation was",
+                        FeatureKind::SubjectlessCase => {
+                            "The `case` expression with no subjects was"
+                        }
+                        FeatureKind::PositionalFieldAccess => {
+                            "Accessing custom type fields by position was"
+                        }
+                        FeatureKind::VariantWithInternalAnnotation => {
+                            "Marking individual custom type variants as internal was"
+                        }
+                        FeatureKind::MustUseAnnotation => "The `@must_use` annotation was",
+                        FeatureKind::DeprecationSince => {
+                            "The `since` label of the `@deprecated` annotation was"
+                        }
                     };
 
                     Diagnostic {
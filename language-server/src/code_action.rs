@@ -17,7 +17,7 @@ use gleam_core::{
     config::PackageConfig,
     exhaustiveness::CompiledCase,
     line_numbers::LineNumbers,
-    parse::{extra::ModuleExtra, lexer::str_to_keyword},
+    parse::{error::ParseErrorType, extra::ModuleExtra, lexer::str_to_keyword},
     strings::to_snake_case,
     type_::{
         self, FieldMap, ModuleValueConstructor, Type, TypeVar, TypedCallArg, ValueConstructor,
@@ -938,6 +938,49 @@ struct MissingImport {
     suggestions: Vec<ImportSuggestion>,
 }
 
+/// Replaces a Python-like import such as `import gleam.io` with the slash
+/// separated module path suggested by the parser.
+///
+pub fn code_action_fix_import_module_separator(
+    params: &CodeActionParams,
+    error: &Option<Error>,
+    actions: &mut Vec<CodeAction>,
+) {
+    let Some(Error::Parse { path, src, errors }) = error else {
+        return;
+    };
+
+    let uri = &params.text_document.uri;
+    if url_from_path(path.as_str()).as_ref() != Some(uri) {
+        return;
+    }
+
+    let line_numbers = LineNumbers::new(src);
+    for error in errors {
+        let ParseErrorType::IncorrectImportModuleSeparator { replacement, .. } = &error.error
+        else {
+            continue;
+        };
+
+        let range = src_span_to_lsp_range(replacement.location, &line_numbers);
+        if !overlaps(params.range, range) {
+            continue;
+        }
+
+        CodeActionBuilder::new(&format!("Replace with `{}`", replacement.text))
+            .kind(CodeActionKind::QUICKFIX)
+            .changes(
+                uri.clone(),
+                vec![TextEdit {
+                    range,
+                    new_text: replacement.text.to_string(),
+                }],
+            )
+            .preferred(true)
+            .push_to(actions);
+    }
+}
+
 struct ImportSuggestion {
     // The name to replace with, if the user made a typo
     name: EcoString,
@@ -8148,6 +8191,7 @@ impl<'a> RemoveUnusedImports<'a> {
                 | type_::Warning::ImplicitlyDiscardedResult { .. }
                 | type_::Warning::UnusedLiteral { .. }
                 | type_::Warning::UnusedValue { .. }
+                | type_::Warning::UnusedMustUseValue { .. }
                 | type_::Warning::NoFieldsRecordUpdate { .. }
                 | type_::Warning::AllFieldsRecordUpdate { .. }
                 | type_::Warning::UnusedType { .. }
@@ -8167,6 +8211,7 @@ impl<'a> RemoveUnusedImports<'a> {
                 | type_::Warning::OpaqueExternalType { .. }
                 | type_::Warning::InternalTypeLeak { .. }
                 | type_::Warning::RedundantAssertAssignment { .. }
+                | type_::Warning::RedundantLetElse { .. }
                 | type_::Warning::AssertAssignmentOnImpossiblePattern { .. }
                 | type_::Warning::TodoOrPanicUsedAsFunction { .. }
                 | type_::Warning::UnreachableCodeAfterPanic { .. }
@@ -44,7 +44,8 @@ use super::{
         RemovePrivateOpaque, RemoveUnreachableCaseClauses, RemoveUnusedImports,
         UseLabelShorthandSyntax, WrapInBlock, code_action_add_missing_patterns,
         code_action_convert_qualified_constructor_to_unqualified,
-        code_action_convert_unqualified_constructor_to_qualified, code_action_import_module,
+        code_action_convert_unqualified_constructor_to_qualified,
+        code_action_fix_import_module_separator, code_action_import_module,
         code_action_inexhaustive_let_to_case,
     },
     compiler::LspProjectCompiler,
@@ -383,8 +384,10 @@ where
     ) -> Response<Option<Vec<CodeAction>>> {
         self.respond(|this| {
             let mut actions = vec![];
+            // Syntax errors can be fixed even if the module has never compiled.
+            code_action_fix_import_module_separator(&params, &this.error, &mut actions);
             let Some(module) = this.module_for_uri(&params.text_document.uri) else {
-                return Ok(None);
+                return Ok((!actions.is_empty()).then_some(actions));
             };
 
             let lines = LineNumbers::new(&module.code);
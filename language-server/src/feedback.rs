@@ -163,6 +163,7 @@ mod tests {
         parse::error::{ParseError, ParseErrorType},
         type_,
     };
+    use vec1::vec1;
 
     #[test]
     fn feedback() {
@@ -276,10 +277,10 @@ mod tests {
         let error = Error::Parse {
             path: file3.clone(),
             src: "blah".into(),
-            error: Box::new(ParseError {
+            errors: vec1![ParseError {
                 error: ParseErrorType::ConcatPatternVariableLeftHandSide,
                 location: SrcSpan::new(1, 4),
-            }),
+            }],
         };
 
         let feedback = book_keeper.build_with_error(
@@ -336,10 +337,10 @@ mod tests {
         let error = Error::Parse {
             path: file1.clone(),
             src: "blah".into(),
-            error: Box::new(ParseError {
+            errors: vec1![ParseError {
                 error: ParseErrorType::ConcatPatternVariableLeftHandSide,
                 location: SrcSpan::new(1, 4),
-            }),
+            }],
         };
 
         let feedback =
@@ -377,10 +378,10 @@ mod tests {
         let error = |file: &camino::Utf8Path| Error::Parse {
             path: file.to_path_buf(),
             src: "blah".into(),
-            error: Box::new(ParseError {
+            errors: vec1![ParseError {
                 error: ParseErrorType::ConcatPatternVariableLeftHandSide,
                 location: SrcSpan::new(1, 4),
-            }),
+            }],
         };
 
         let feedback =
@@ -420,10 +421,10 @@ mod tests {
         let error = Error::Parse {
             path: file1.clone(),
             src: "blah".into(),
-            error: Box::new(ParseError {
+            errors: vec1![ParseError {
                 error: ParseErrorType::ConcatPatternVariableLeftHandSide,
                 location: SrcSpan::new(1, 4),
-            }),
+            }],
         };
 
         let feedback =
@@ -11625,3 +11625,46 @@ pub fn wibble() {
         find_position_of("x").to_selection()
     );
 }
+
+#[test]
+fn fix_pythonic_import() {
+    assert_code_action!(
+        "Replace with `gleam/io`",
+        r#"
+import gleam.io
+
+pub fn main() {
+  io.println("Hello")
+}"#,
+        find_position_of("gleam").to_selection()
+    );
+}
+
+#[test]
+fn fix_pythonic_nested_import() {
+    assert_code_action!(
+        "Replace with `one/two/three`",
+        r#"
+import one.two.three as four
+
+pub fn main() {
+  four.main()
+}"#,
+        find_position_of("three").to_selection()
+    );
+}
+
+#[test]
+fn no_fix_pythonic_import_outside_of_module_path() {
+    let title = "Replace with `gleam/io`";
+    assert_no_code_actions!(
+        title,
+        r#"
+import gleam.io
+
+pub fn main() {
+  io.println("Hello")
+}"#,
+        find_position_of("main").to_selection()
+    );
+}
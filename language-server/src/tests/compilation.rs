@@ -37,12 +37,14 @@ fn compile_error_in_src() {
     let io = LanguageServerTestIO::new();
     let mut engine = setup_engine(&io);
 
-    _ = io.src_module("app/error", "pub type Error {");
+    let path = io.src_module("app/error", "pub type Error {");
 
+    // The module is still compiled so that the language server can use the
+    // definitions that could be parsed.
     let response = engine.compile_please();
     assert!(response.result.is_err());
     assert!(response.warnings.is_empty());
-    assert_eq!(response.compilation, Compilation::Yes(vec![]));
+    assert_eq!(response.compilation, Compilation::Yes(vec![path]));
 
     drop(engine);
     let actions = io.into_actions();
@@ -69,12 +71,12 @@ fn compile_error_in_test() {
     let io = LanguageServerTestIO::new();
     let mut engine = setup_engine(&io);
 
-    _ = io.test_module("app/error", "pub type Error {");
+    let path = io.test_module("app/error", "pub type Error {");
 
     let response = engine.compile_please();
     assert!(response.result.is_err());
     assert!(response.warnings.is_empty());
-    assert_eq!(response.compilation, Compilation::Yes(vec![]));
+    assert_eq!(response.compilation, Compilation::Yes(vec![path]));
 
     drop(engine);
     let actions = io.into_actions();
@@ -101,12 +103,12 @@ fn compile_error_in_dev() {
     let io = LanguageServerTestIO::new();
     let mut engine = setup_engine(&io);
 
-    _ = io.dev_module("app/error", "pub type Error {");
+    let path = io.dev_module("app/error", "pub type Error {");
 
     let response = engine.compile_please();
     assert!(response.result.is_err());
     assert!(response.warnings.is_empty());
-    assert_eq!(response.compilation, Compilation::Yes(vec![]));
+    assert_eq!(response.compilation, Compilation::Yes(vec![path]));
 
     drop(engine);
     let actions = io.into_actions();
@@ -1186,6 +1186,32 @@ fn internal_values_from_a_dependency_are_ignored() {
     assert_completion!(TestProject::for_source("import dep").add_dep_module("dep", dep));
 }
 
+#[test]
+fn internal_variants_from_root_package_are_in_the_completions() {
+    let dep = "
+pub type Wibble {
+  Wibble
+  @internal
+  Wobble
+}
+";
+
+    assert_completion!(TestProject::for_source("import dep").add_module("dep", dep));
+}
+
+#[test]
+fn internal_variants_from_a_dependency_are_ignored() {
+    let dep = "
+pub type Wibble {
+  Wibble
+  @internal
+  Wobble
+}
+";
+
+    assert_completion!(TestProject::for_source("import dep").add_dep_module("dep", dep));
+}
+
 #[test]
 fn completions_for_an_import() {
     let code = "import dep
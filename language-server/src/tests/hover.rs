@@ -97,6 +97,26 @@ fn main() {
     );
 }
 
+#[test]
+fn hover_function_defined_after_unclosed_block() {
+    assert_hover!(
+        "
+fn main() {
+  let x = {
+    1
+
+fn my_fn() {
+  Nil
+}
+
+fn wibble() {
+  my_fn
+}
+",
+        find_position_of("my_fn").under_char('y').nth_occurrence(2)
+    );
+}
+
 // https://github.com/gleam-lang/gleam/issues/2654
 #[test]
 fn hover_local_function_in_pipe() {